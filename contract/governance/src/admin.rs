@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage_types::DataKey;
+
+pub fn read_administrator(e: &Env) -> Address {
+    let key = DataKey::Admin;
+    e.storage().instance().get(&key).unwrap()
+}
+
+pub fn write_administrator(e: &Env, id: &Address) {
+    let key = DataKey::Admin;
+    e.storage().instance().set(&key, id);
+}
@@ -0,0 +1,44 @@
+use crate::checkpoints::write_checkpoint;
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Env};
+
+pub fn read_balance(e: &Env, addr: Address) -> i128 {
+    let key = DataKey::Balance(addr);
+    if let Some(balance) = e.storage().persistent().get::<DataKey, i128>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        balance
+    } else {
+        0
+    }
+}
+
+fn write_balance(e: &Env, addr: Address, amount: i128) {
+    let key = DataKey::Balance(addr.clone());
+    e.storage().persistent().set(&key, &amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    write_checkpoint(e, addr, amount);
+}
+
+pub fn receive_balance(e: &Env, addr: Address, amount: i128) {
+    // Zero amounts would only grow the holder's checkpoint history
+    if amount == 0 {
+        return;
+    }
+    let balance = read_balance(e, addr.clone());
+    write_balance(e, addr, balance + amount);
+}
+
+pub fn spend_balance(e: &Env, addr: Address, amount: i128) {
+    let balance = read_balance(e, addr.clone());
+    if balance < amount {
+        panic!("insufficient balance");
+    }
+    if amount == 0 {
+        return;
+    }
+    write_balance(e, addr, balance - amount);
+}
@@ -0,0 +1,83 @@
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Voting power of a holder from `timestamp` until the next checkpoint.
+#[derive(Clone)]
+#[contracttype]
+pub struct Checkpoint {
+    pub timestamp: u64,
+    pub votes: i128,
+}
+
+fn read_checkpoint_count(e: &Env, addr: Address) -> u32 {
+    let key = DataKey::CheckpointCount(addr);
+    if let Some(count) = e.storage().persistent().get::<DataKey, u32>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        count
+    } else {
+        0
+    }
+}
+
+fn read_checkpoint(e: &Env, addr: Address, index: u32) -> Checkpoint {
+    let key = DataKey::Checkpoint(addr, index);
+    let checkpoint = e.storage().persistent().get::<DataKey, Checkpoint>(&key).unwrap();
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    checkpoint
+}
+
+/// Record a holder's new balance. Changes within one ledger timestamp
+/// overwrite each other.
+pub fn write_checkpoint(e: &Env, addr: Address, votes: i128) {
+    let timestamp = e.ledger().timestamp();
+    let count = read_checkpoint_count(e, addr.clone());
+
+    // Each checkpoint is its own entry, so a long history never has to be
+    // loaded as a whole
+    let same_ledger =
+        count > 0 && read_checkpoint(e, addr.clone(), count - 1).timestamp == timestamp;
+    let index = if same_ledger {
+        count - 1
+    } else {
+        let key = DataKey::CheckpointCount(addr.clone());
+        e.storage().persistent().set(&key, &(count + 1));
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        count
+    };
+
+    let key = DataKey::Checkpoint(addr, index);
+    e.storage()
+        .persistent()
+        .set(&key, &Checkpoint { timestamp, votes });
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+/// Voting power a holder had before `timestamp`, i.e. at the end of the
+/// last ledger that closed earlier.
+pub fn read_votes_before(e: &Env, addr: Address, timestamp: u64) -> i128 {
+    // Binary search for the last checkpoint strictly before `timestamp`
+    let mut low = 0;
+    let mut high = read_checkpoint_count(e, addr.clone());
+    while low < high {
+        let mid = (low + high) / 2;
+        if read_checkpoint(e, addr.clone(), mid).timestamp < timestamp {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low == 0 {
+        0
+    } else {
+        read_checkpoint(e, addr, low - 1).votes
+    }
+}
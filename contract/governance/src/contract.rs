@@ -0,0 +1,509 @@
+//! Governance token of the prediction market: a Soroban token whose balance
+//! history is checkpointed, so holders can vote on disputed market outcomes
+//! with the balance they had when the dispute was opened.
+use crate::admin::{read_administrator, write_administrator};
+use crate::allowance::{read_allowance, spend_allowance, write_allowance};
+use crate::balance::{read_balance, receive_balance, spend_balance};
+use crate::checkpoints::read_votes_before;
+use crate::dispute::{
+    has_voted, read_dispute, read_market_contract, read_proposal_threshold, read_quorum,
+    read_voting_period, write_dispute, write_market_contract, write_proposal_threshold,
+    write_quorum, write_voted, write_voting_period, Dispute, DisputeStatus,
+    PredictionMarketClient, PredictionSide, ResolutionEvidence,
+};
+use crate::metadata::{read_decimal, read_name, read_symbol, write_metadata};
+#[cfg(test)]
+use crate::storage_types::{AllowanceDataKey, AllowanceValue, DataKey};
+use crate::storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD, MAX_DISPUTE_ROUNDS};
+use soroban_sdk::{
+    contract, contractevent, contractimpl, token::TokenInterface, Address, Env, MuxedAddress,
+    String,
+};
+use soroban_token_sdk::events;
+use soroban_token_sdk::metadata::TokenMetadata;
+
+fn check_nonnegative_amount(amount: i128) {
+    if amount < 0 {
+        panic!("negative amount is not allowed: {}", amount)
+    }
+}
+
+#[contract]
+pub struct GovernanceToken;
+
+// SetAdmin is not a standardized token event, so we just define a custom event
+// for our token.
+#[contractevent(data_format = "single-value")]
+pub struct SetAdmin {
+    #[topic]
+    admin: Address,
+    new_admin: Address,
+}
+
+/// Emitted when holders start voting on a market's outcome.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeOpened {
+    #[topic]
+    pub market_id: u64,
+    pub proposer: Address,
+    pub round: u32,
+    pub voting_ends: u64,
+    pub contested: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCast {
+    #[topic]
+    pub market_id: u64,
+    pub voter: Address,
+    pub side: PredictionSide,
+    pub votes: i128,
+}
+
+/// Emitted when the outcome of a vote is delivered to the prediction market.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeExecuted {
+    #[topic]
+    pub market_id: u64,
+    pub outcome: PredictionSide,
+}
+
+/// Emitted when a vote ends without an outcome the market accepted. A
+/// contested resolution is upheld for good, an unresolved market can be
+/// disputed again.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeFailed {
+    #[topic]
+    pub market_id: u64,
+    pub yes_votes: i128,
+    pub no_votes: i128,
+}
+
+#[contractimpl]
+impl GovernanceToken {
+    pub fn __constructor(e: Env, admin: Address, decimal: u32, name: String, symbol: String) {
+        if decimal > 18 {
+            panic!("Decimal must not be greater than 18");
+        }
+        write_administrator(&e, &admin);
+        write_metadata(
+            &e,
+            TokenMetadata {
+                decimal,
+                name,
+                symbol,
+            },
+        )
+    }
+
+    pub fn mint(e: Env, to: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        receive_balance(&e, to.clone(), amount);
+        events::MintWithAmountOnly { to, amount }.publish(&e);
+    }
+
+    pub fn set_admin(e: Env, new_admin: Address) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_administrator(&e, &new_admin);
+        SetAdmin { admin, new_admin }.publish(&e);
+    }
+
+    /// Set the prediction market whose disputes this token votes on. The
+    /// market admin must register this contract as its resolver.
+    pub fn set_market_contract(e: Env, market: Address) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        write_market_contract(&e, &market);
+    }
+
+    pub fn set_voting_period(e: Env, period: u64) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        write_voting_period(&e, period);
+    }
+
+    pub fn set_quorum(e: Env, quorum: i128) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        write_quorum(&e, quorum);
+    }
+
+    /// Set the voting power a holder needs before a dispute is opened to open it
+    pub fn set_proposal_threshold(e: Env, threshold: i128) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        write_proposal_threshold(&e, threshold);
+    }
+
+    /// Open a vote on the outcome of a market. Holders with enough past
+    /// voting power can open one, and open it again after a failed vote up to
+    /// `MAX_DISPUTE_ROUNDS` times. On a resolved market the vote contests the
+    /// resolution, which freezes its payouts until executed; an upheld
+    /// resolution can't be contested again.
+    pub fn open_dispute(e: Env, proposer: Address, market_id: u64) {
+        proposer.require_auth();
+
+        let market = match read_market_contract(&e) {
+            Some(market) => market,
+            None => panic!("market contract not set"),
+        };
+
+        if read_quorum(&e).is_none() {
+            panic!("quorum not set");
+        }
+
+        let threshold = match read_proposal_threshold(&e) {
+            Some(threshold) => threshold,
+            None => panic!("proposal threshold not set"),
+        };
+
+        // Tokens bought for the occasion don't count
+        let opened_at = e.ledger().timestamp();
+        if read_votes_before(&e, proposer.clone(), opened_at) < threshold {
+            panic!("not enough voting power to open a dispute");
+        }
+
+        let round = match read_dispute(&e, market_id) {
+            Some(dispute) => match dispute.status {
+                DisputeStatus::Open => panic!("dispute already open"),
+                DisputeStatus::Executed => panic!("dispute already executed"),
+                DisputeStatus::Upheld => panic!("resolution already upheld"),
+                DisputeStatus::Failed if dispute.round >= MAX_DISPUTE_ROUNDS => {
+                    panic!("dispute rounds exhausted")
+                }
+                DisputeStatus::Failed => dispute.round + 1,
+            },
+            None => 1,
+        };
+
+        let market_client = PredictionMarketClient::new(&e, &market);
+        let contested = market_client.get_winning_side(&market_id).is_some();
+        if contested {
+            market_client.contest_resolution(&e.current_contract_address(), &market_id);
+        }
+
+        let voting_ends = opened_at + read_voting_period(&e);
+        let dispute = Dispute {
+            round,
+            opened_at,
+            voting_ends,
+            yes_votes: 0,
+            no_votes: 0,
+            contested,
+            status: DisputeStatus::Open,
+        };
+        write_dispute(&e, market_id, &dispute);
+
+        DisputeOpened {
+            market_id,
+            proposer,
+            round,
+            voting_ends,
+            contested,
+        }
+        .publish(&e);
+    }
+
+    /// Vote on a disputed market with the balance held before it was opened
+    pub fn vote(e: Env, voter: Address, market_id: u64, side: PredictionSide) -> i128 {
+        voter.require_auth();
+
+        let mut dispute = match read_dispute(&e, market_id) {
+            Some(dispute) => dispute,
+            None => panic!("dispute not found"),
+        };
+
+        if dispute.status != DisputeStatus::Open || e.ledger().timestamp() >= dispute.voting_ends {
+            panic!("voting closed");
+        }
+
+        if has_voted(&e, market_id, dispute.round, voter.clone()) {
+            panic!("already voted");
+        }
+
+        let votes = read_votes_before(&e, voter.clone(), dispute.opened_at);
+        if votes <= 0 {
+            panic!("no voting power");
+        }
+
+        match side {
+            PredictionSide::Yes => dispute.yes_votes += votes,
+            PredictionSide::No => dispute.no_votes += votes,
+        }
+        write_dispute(&e, market_id, &dispute);
+        write_voted(&e, market_id, dispute.round, voter.clone());
+
+        VoteCast {
+            market_id,
+            voter,
+            side,
+            votes,
+        }
+        .publish(&e);
+
+        votes
+    }
+
+    /// Close a finished vote. Callable by anyone once voting has closed.
+    /// A majority reaching the quorum resolves the market with its outcome,
+    /// replacing a contested resolution. Otherwise the dispute fails and
+    /// returns None: a contested resolution is upheld for good, an unresolved
+    /// market can be disputed again.
+    pub fn execute_dispute(e: Env, market_id: u64) -> Option<PredictionSide> {
+        let mut dispute = match read_dispute(&e, market_id) {
+            Some(dispute) => dispute,
+            None => panic!("dispute not found"),
+        };
+
+        if dispute.status != DisputeStatus::Open {
+            panic!("dispute already closed");
+        }
+
+        if e.ledger().timestamp() < dispute.voting_ends {
+            panic!("voting still open");
+        }
+
+        let quorum = read_quorum(&e).unwrap();
+        let outcome = if dispute.yes_votes + dispute.no_votes < quorum {
+            None
+        } else if dispute.yes_votes > dispute.no_votes {
+            Some(PredictionSide::Yes)
+        } else if dispute.no_votes > dispute.yes_votes {
+            Some(PredictionSide::No)
+        } else {
+            None
+        };
+
+        let market = PredictionMarketClient::new(&e, &read_market_contract(&e).unwrap());
+        let resolver = e.current_contract_address();
+
+        // The market may still reject the outcome, e.g. before `resolve_after`
+        let delivered = match outcome {
+            Some(outcome) => market
+                .try_resolve_market(
+                    &resolver,
+                    &market_id,
+                    &outcome,
+                    &ResolutionEvidence {
+                        reference: String::from_str(&e, ""),
+                        justification: String::from_str(
+                            &e,
+                            "Majority of a token holder dispute vote",
+                        ),
+                    },
+                )
+                .is_ok(),
+            None => false,
+        };
+
+        if delivered {
+            dispute.status = DisputeStatus::Executed;
+            write_dispute(&e, market_id, &dispute);
+
+            let outcome = outcome.unwrap();
+            DisputeExecuted { market_id, outcome }.publish(&e);
+
+            return Some(outcome);
+        }
+
+        // Release the payouts a contested resolution has frozen
+        dispute.status = if dispute.contested {
+            let winning_side = market.get_winning_side(&market_id).unwrap();
+            market.resolve_market(
+                &resolver,
+                &market_id,
+                &winning_side,
+                &ResolutionEvidence {
+                    reference: String::from_str(&e, ""),
+                    justification: String::from_str(&e, "Upheld by a token holder dispute vote"),
+                },
+            );
+            DisputeStatus::Upheld
+        } else {
+            DisputeStatus::Failed
+        };
+        write_dispute(&e, market_id, &dispute);
+
+        DisputeFailed {
+            market_id,
+            yes_votes: dispute.yes_votes,
+            no_votes: dispute.no_votes,
+        }
+        .publish(&e);
+
+        None
+    }
+
+    pub fn get_dispute(e: Env, market_id: u64) -> Option<Dispute> {
+        read_dispute(&e, market_id)
+    }
+
+    /// Voting power a holder had before `timestamp`
+    pub fn get_past_votes(e: Env, id: Address, timestamp: u64) -> i128 {
+        read_votes_before(&e, id, timestamp)
+    }
+
+    pub fn get_market_contract(e: Env) -> Option<Address> {
+        read_market_contract(&e)
+    }
+
+    pub fn get_voting_period(e: Env) -> u64 {
+        read_voting_period(&e)
+    }
+
+    pub fn get_quorum(e: Env) -> Option<i128> {
+        read_quorum(&e)
+    }
+
+    pub fn get_proposal_threshold(e: Env) -> Option<i128> {
+        read_proposal_threshold(&e)
+    }
+}
+
+#[cfg(test)]
+#[contractimpl]
+impl GovernanceToken {
+    pub fn get_allowance(e: Env, from: Address, spender: Address) -> Option<AllowanceValue> {
+        let key = DataKey::Allowance(AllowanceDataKey { from, spender });
+        e.storage().temporary().get::<_, AllowanceValue>(&key)
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for GovernanceToken {
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_allowance(&e, from, spender).amount
+    }
+
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+
+        check_nonnegative_amount(amount);
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_allowance(&e, from.clone(), spender.clone(), amount, expiration_ledger);
+        events::Approve {
+            from,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(&e);
+    }
+
+    fn balance(e: Env, id: Address) -> i128 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_balance(&e, id)
+    }
+
+    fn transfer(e: Env, from: Address, to_muxed: MuxedAddress, amount: i128) {
+        from.require_auth();
+
+        check_nonnegative_amount(amount);
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        spend_balance(&e, from.clone(), amount);
+        let to: Address = to_muxed.address();
+        receive_balance(&e, to.clone(), amount);
+        events::Transfer {
+            from,
+            to,
+            to_muxed_id: to_muxed.id(),
+            amount,
+        }
+        .publish(&e);
+    }
+
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+
+        check_nonnegative_amount(amount);
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        spend_allowance(&e, from.clone(), spender, amount);
+        spend_balance(&e, from.clone(), amount);
+        receive_balance(&e, to.clone(), amount);
+        events::Transfer {
+            from,
+            to,
+            // `transfer_from` does not support muxed destination.
+            to_muxed_id: None,
+            amount,
+        }
+        .publish(&e);
+    }
+
+    fn burn(e: Env, from: Address, amount: i128) {
+        from.require_auth();
+
+        check_nonnegative_amount(amount);
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        spend_balance(&e, from.clone(), amount);
+        events::Burn { from, amount }.publish(&e);
+    }
+
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+
+        check_nonnegative_amount(amount);
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        spend_allowance(&e, from.clone(), spender, amount);
+        spend_balance(&e, from.clone(), amount);
+        events::Burn { from, amount }.publish(&e);
+    }
+
+    fn decimals(e: Env) -> u32 {
+        read_decimal(&e)
+    }
+
+    fn name(e: Env) -> String {
+        read_name(&e)
+    }
+
+    fn symbol(e: Env) -> String {
+        read_symbol(&e)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::storage_types::{DataKey, DEFAULT_VOTING_PERIOD};
+use soroban_sdk::{contractclient, contracttype, Address, Env, String};
+
+/// Market outcome, mirrors `PredictionSide` of the prediction market contract.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum PredictionSide {
+    Yes,
+    No,
+}
+
+/// Mirrors `ResolutionEvidence` of the prediction market contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolutionEvidence {
+    pub reference: String,
+    pub justification: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum DisputeStatus {
+    Open,
+    Executed, // The outcome was delivered to the market
+    Failed,   // No quorum, a tie or rejected by the market; can be reopened
+    Upheld,   // Failed vote on a contested resolution, which is now final
+}
+
+/// A token holder vote on the outcome of a disputed market.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Dispute {
+    pub round: u32,       // Number of times the market has been disputed
+    pub opened_at: u64,   // Votes are weighted by balances before this time
+    pub voting_ends: u64, // Unix timestamp
+    pub yes_votes: i128,
+    pub no_votes: i128,
+    pub contested: bool,  // Whether the vote contests an existing resolution
+    pub status: DisputeStatus,
+}
+
+/// The part of the prediction market contract used to deliver outcomes.
+#[contractclient(name = "PredictionMarketClient")]
+pub trait PredictionMarketInterface {
+    fn resolve_market(
+        e: Env,
+        admin: Address,
+        market_id: u64,
+        winning_side: PredictionSide,
+        evidence: ResolutionEvidence,
+    );
+
+    fn contest_resolution(e: Env, resolver: Address, market_id: u64);
+
+    fn get_winning_side(e: Env, market_id: u64) -> Option<PredictionSide>;
+}
+
+pub fn read_market_contract(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::MarketContract)
+}
+
+pub fn write_market_contract(e: &Env, market: &Address) {
+    e.storage().instance().set(&DataKey::MarketContract, market);
+}
+
+pub fn read_voting_period(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DataKey::VotingPeriod)
+        .unwrap_or(DEFAULT_VOTING_PERIOD)
+}
+
+pub fn write_voting_period(e: &Env, period: u64) {
+    if period == 0 {
+        panic!("voting period must be positive");
+    }
+    e.storage().instance().set(&DataKey::VotingPeriod, &period);
+}
+
+pub fn read_quorum(e: &Env) -> Option<i128> {
+    e.storage().instance().get(&DataKey::Quorum)
+}
+
+pub fn write_quorum(e: &Env, quorum: i128) {
+    if quorum <= 0 {
+        panic!("quorum must be positive");
+    }
+    e.storage().instance().set(&DataKey::Quorum, &quorum);
+}
+
+pub fn read_proposal_threshold(e: &Env) -> Option<i128> {
+    e.storage().instance().get(&DataKey::ProposalThreshold)
+}
+
+pub fn write_proposal_threshold(e: &Env, threshold: i128) {
+    if threshold <= 0 {
+        panic!("proposal threshold must be positive");
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::ProposalThreshold, &threshold);
+}
+
+pub fn read_dispute(e: &Env, market_id: u64) -> Option<Dispute> {
+    e.storage().persistent().get(&DataKey::Dispute(market_id))
+}
+
+pub fn write_dispute(e: &Env, market_id: u64, dispute: &Dispute) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Dispute(market_id), dispute);
+}
+
+pub fn has_voted(e: &Env, market_id: u64, round: u32, voter: Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::Voted(market_id, round, voter))
+}
+
+pub fn write_voted(e: &Env, market_id: u64, round: u32, voter: Address) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Voted(market_id, round, voter), &true);
+}
@@ -0,0 +1,16 @@
+#![no_std]
+
+mod admin;
+mod allowance;
+mod balance;
+mod checkpoints;
+mod contract;
+mod dispute;
+mod metadata;
+mod storage_types;
+mod test;
+
+pub use crate::contract::{GovernanceToken, GovernanceTokenClient};
+pub use crate::dispute::{
+    Dispute, DisputeStatus, PredictionMarketInterface, PredictionSide, ResolutionEvidence,
+};
@@ -0,0 +1,41 @@
+use soroban_sdk::{contracttype, Address};
+
+pub(crate) const DEFAULT_VOTING_PERIOD: u64 = 3 * 24 * 60 * 60; // 3 days
+pub(crate) const MAX_DISPUTE_ROUNDS: u32 = 3;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub(crate) const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Allowance(AllowanceDataKey),
+    Balance(Address),
+    State(Address),
+    Admin,
+    Checkpoint(Address, u32), // Holder -> Index -> Checkpoint
+    CheckpointCount(Address), // Holder -> Number of checkpoints
+    MarketContract,           // PredictionMarket this token resolves disputes for
+    VotingPeriod,             // Seconds a dispute is open for votes
+    Quorum,                   // Minimum votes for a dispute to be executed
+    ProposalThreshold,        // Minimum past votes to open a dispute
+    Dispute(u64),             // MarketId -> Dispute
+    Voted(u64, u32, Address), // MarketId -> Round -> Voter -> Whether they voted
+}
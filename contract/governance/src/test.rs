@@ -0,0 +1,422 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::storage_types::DataKey;
+use crate::{contract::GovernanceToken, DisputeStatus, GovernanceTokenClient, PredictionSide};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger},
+    Address, Env, FromVal, IntoVal, String, Symbol,
+};
+
+/// Stands in for a prediction market whose markets are all unresolved.
+#[contract]
+struct UnresolvedMarket;
+
+#[contractimpl]
+impl UnresolvedMarket {
+    pub fn get_winning_side(_e: Env, _market_id: u64) -> Option<PredictionSide> {
+        None
+    }
+}
+
+fn create_token<'a>(e: &Env, admin: &Address) -> GovernanceTokenClient<'a> {
+    let token_contract = e.register(
+        GovernanceToken,
+        (
+            admin,
+            7_u32,
+            String::from_val(e, &"name"),
+            String::from_val(e, &"symbol"),
+        ),
+    );
+    GovernanceTokenClient::new(e, &token_contract)
+}
+
+#[test]
+fn test() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin1 = Address::generate(&e);
+    let admin2 = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin1);
+
+    token.mint(&user1, &1000);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            admin1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("mint"),
+                    (&user1, 1000_i128).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.balance(&user1), 1000);
+
+    token.approve(&user2, &user3, &500, &200);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user2.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("approve"),
+                    (&user2, &user3, 500_i128, 200_u32).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.allowance(&user2, &user3), 500);
+
+    token.transfer(&user1, &user2, &600);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("transfer"),
+                    (&user1, &user2, 600_i128).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.balance(&user1), 400);
+    assert_eq!(token.balance(&user2), 600);
+
+    token.transfer_from(&user3, &user2, &user1, &400);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user3.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "transfer_from"),
+                    (&user3, &user2, &user1, 400_i128).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.balance(&user1), 800);
+    assert_eq!(token.balance(&user2), 200);
+
+    token.transfer(&user1, &user3, &300);
+    assert_eq!(token.balance(&user1), 500);
+    assert_eq!(token.balance(&user3), 300);
+
+    token.set_admin(&admin2);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            admin1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("set_admin"),
+                    (&admin2,).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+
+    // Increase to 500
+    token.approve(&user2, &user3, &500, &200);
+    assert_eq!(token.allowance(&user2, &user3), 500);
+    token.approve(&user2, &user3, &0, &200);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user2.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("approve"),
+                    (&user2, &user3, 0_i128, 200_u32).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.allowance(&user2, &user3), 0);
+}
+
+#[test]
+fn test_burn() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    assert_eq!(token.balance(&user1), 1000);
+
+    token.approve(&user1, &user2, &500, &200);
+    assert_eq!(token.allowance(&user1, &user2), 500);
+
+    token.burn_from(&user2, &user1, &500);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user2.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("burn_from"),
+                    (&user2, &user1, 500_i128).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+
+    assert_eq!(token.allowance(&user1, &user2), 0);
+    assert_eq!(token.balance(&user1), 500);
+    assert_eq!(token.balance(&user2), 0);
+
+    token.burn(&user1, &500);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("burn"),
+                    (&user1, 500_i128).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+
+    assert_eq!(token.balance(&user1), 0);
+    assert_eq!(token.balance(&user2), 0);
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance")]
+fn transfer_insufficient_balance() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    assert_eq!(token.balance(&user1), 1000);
+
+    token.transfer(&user1, &user2, &1001);
+}
+
+#[test]
+#[should_panic(expected = "insufficient allowance")]
+fn transfer_from_insufficient_allowance() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    assert_eq!(token.balance(&user1), 1000);
+
+    token.approve(&user1, &user3, &100, &200);
+    assert_eq!(token.allowance(&user1, &user3), 100);
+
+    token.transfer_from(&user3, &user1, &user2, &101);
+}
+
+#[test]
+#[should_panic(expected = "Decimal must not be greater than 18")]
+fn decimal_is_over_eighteen() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let _ = GovernanceTokenClient::new(
+        &e,
+        &e.register(
+            GovernanceToken,
+            (
+                admin,
+                19_u32,
+                String::from_val(&e, &"name"),
+                String::from_val(&e, &"symbol"),
+            ),
+        ),
+    );
+}
+
+#[test]
+fn test_zero_allowance() {
+    // Here we test that transfer_from with a 0 amount does not create an empty allowance
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let from = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.transfer_from(&spender, &from, &spender, &0);
+    assert!(token.get_allowance(&from, &spender).is_none());
+}
+
+#[test]
+fn test_vote_checkpoints() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    // Several changes in one ledger keep a single checkpoint
+    token.transfer(&user1, &user2, &100);
+
+    e.ledger().set_timestamp(2000);
+    token.transfer(&user1, &user2, &400);
+
+    e.ledger().set_timestamp(3000);
+    token.burn(&user2, &500);
+
+    // Zero-amount transfers don't grow the recipient's history
+    e.ledger().set_timestamp(4000);
+    token.transfer(&user2, &user1, &0);
+    let count: u32 = e.as_contract(&token.address, || {
+        e.storage()
+            .persistent()
+            .get(&DataKey::CheckpointCount(user1.clone()))
+            .unwrap()
+    });
+    assert_eq!(count, 2);
+
+    assert_eq!(token.get_past_votes(&user1, &1000), 0);
+    assert_eq!(token.get_past_votes(&user1, &1001), 900);
+    assert_eq!(token.get_past_votes(&user1, &2000), 900);
+    assert_eq!(token.get_past_votes(&user1, &2001), 500);
+    assert_eq!(token.get_past_votes(&user2, &2001), 500);
+    assert_eq!(token.get_past_votes(&user2, &3001), 0);
+    assert_eq!(token.balance(&user1), 500);
+}
+
+#[test]
+fn test_dispute_votes_use_balances_before_opening() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let market = e.register(UnresolvedMarket, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.set_market_contract(&market);
+    token.set_voting_period(&100);
+    token.mint(&user1, &600);
+
+    // Without a quorum a single token could decide a market
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token.open_dispute(&user1, &7);
+    }));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token.set_quorum(&0);
+    }));
+    assert!(result.is_err());
+    token.set_quorum(&500);
+    token.set_proposal_threshold(&100);
+
+    // Voting power is needed before the dispute, not bought for it
+    e.ledger().set_timestamp(2000);
+    token.mint(&user2, &100);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token.open_dispute(&user2, &7);
+    }));
+    assert!(result.is_err());
+    token.burn(&user2, &100);
+
+    token.open_dispute(&user1, &7);
+
+    let dispute = token.get_dispute(&7).unwrap();
+    assert_eq!(dispute.round, 1);
+    assert_eq!(dispute.opened_at, 2000);
+    assert_eq!(dispute.voting_ends, 2100);
+    assert!(!dispute.contested);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token.open_dispute(&user1, &7);
+    }));
+    assert!(result.is_err());
+
+    // Tokens moved after the dispute opened can't be voted twice
+    assert_eq!(token.vote(&user1, &7, &PredictionSide::No), 600);
+    token.transfer(&user1, &user2, &600);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token.vote(&user2, &7, &PredictionSide::Yes);
+    }));
+    assert!(result.is_err());
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token.vote(&user1, &7, &PredictionSide::No);
+    }));
+    assert!(result.is_err());
+
+    assert_eq!(token.get_dispute(&7).unwrap().no_votes, 600);
+
+    // Results can only be delivered once voting has closed
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token.execute_dispute(&7);
+    }));
+    assert!(result.is_err());
+
+    // A majority the market rejects fails the dispute instead of locking it
+    e.ledger().set_timestamp(2100);
+    assert_eq!(token.execute_dispute(&7), None);
+    assert_eq!(token.get_dispute(&7).unwrap().status, DisputeStatus::Failed);
+
+    // A new round starts from fresh votes
+    token.open_dispute(&user2, &7);
+    let dispute = token.get_dispute(&7).unwrap();
+    assert_eq!(dispute.round, 2);
+    assert_eq!(dispute.no_votes, 0);
+    assert_eq!(token.vote(&user2, &7, &PredictionSide::Yes), 600);
+
+    // Failed rounds can't be reopened forever
+    e.ledger().set_timestamp(2200);
+    assert_eq!(token.execute_dispute(&7), None);
+    token.open_dispute(&user2, &7);
+    e.ledger().set_timestamp(2300);
+    assert_eq!(token.execute_dispute(&7), None);
+    assert_eq!(token.get_dispute(&7).unwrap().round, 3);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token.open_dispute(&user2, &7);
+    }));
+    assert!(result.is_err());
+}
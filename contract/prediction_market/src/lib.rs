@@ -1,7 +1,9 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contractmeta, contracttype, token, Address, Env, String,
+    contract, contractclient, contracterror, contractevent, contractimpl, contractmeta,
+    contracttype, panic_with_error, token, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String,
+    Vec,
 };
 
 mod test;
@@ -15,9 +17,63 @@ pub enum DataKey {
     UserPredictions(Address, u64), // User -> MarketId -> Prediction
     PlatformFeeRate,
     WinningSide(u64),              // MarketId -> WinningSide
+    Settlement(u64),               // MarketId -> MarketSettlement
+    CollectedFees(Address),        // Token -> Platform fees held by the contract
+    ClaimWindow,                   // Seconds winners have to claim after resolution
+    KeeperTipRate,                 // Basis points of winnings paid to keepers in claim_for
+    Series(u64),                   // SeriesId -> MarketSeries
+    SeriesCount,
+    SeriesMarket(u64, u32),        // SeriesId -> Round -> MarketId
+    LpPositions(Address, u64),     // Provider -> MarketId -> LpPosition
+    LpFeeShare,                    // Basis points of platform fees paid to a market's LPs
+    ResolutionWindow,              // Default seconds between resolve_after and resolve_deadline
+    Commitments(Address, u64),     // User -> MarketId -> Commitment
+    CommitPenaltyRate,             // Basis points of unrevealed deposits kept as fees
+    Referrals(Address, u64),       // User -> MarketId -> Referrer
+    ReferrerStats(Address, Address), // Referrer -> Token -> ReferrerStats
+    ReferralShare,                 // Basis points of platform fees paid to referrers
+    FeeTiers(FeeTierKind),         // Kind -> Vec<FeeTier>, ascending thresholds
+    UserVolume(Address),           // User -> Cumulative stake placed
+    StakingToken,                  // Governance token staked for fee discounts
+    StakedBalance(Address),        // User -> Staked governance tokens
+    FeeBasis,                      // Whether fees apply to the gross payout or the profit
+    Treasury,                      // Contract collected fees are forwarded to
+    InsuranceShare,                // Basis points of platform fees set aside for insurance
+    InsuranceCap,                  // Basis points of a market's pool payable per incident
+    InsuranceFund(Address),        // Token -> Insurance pool held by the contract
+    Resolver,                      // Contract allowed to resolve markets besides the admin
+    Signers,                       // Vec<Address> approving sensitive admin actions
+    Threshold,                     // Approvals needed to execute a proposal
+    SignersVersion,                // Bumped on every signer change, voids older proposals
+    Proposals(u64),                // ProposalId -> Proposal
+    ProposalCount,
+    PendingAdmin,                  // Address that can accept the admin role
+    PendingFeeRate,                // Fee rate increase waiting out its timelock
+    CreationPolicy,                // Who may create markets
+    Allowlisted(Address),          // Creator -> Allowed to create markets
+    CreatorReputation(Address),    // Creator -> Markets of theirs that resolved
+    MinCreatorReputation,          // Reputation open-mode creators need
+    Moderators(Address),           // Address -> Can delist markets
+    Flags(u64, Address),           // MarketId -> User -> MarketFlag
+    FlagCount(u64),                // MarketId -> Number of users who flagged it
+    Delistings(u64),               // MarketId -> Delisting
+    Resolutions(u64),              // MarketId -> Resolution
+    CommitmentCount(u64),          // MarketId -> Commitments made
+    StakeUnlocksAt(Address),       // User -> Time staked tokens unlock
+    Contested(u64),                // MarketId -> Resolution under a dispute vote, false once settled
 }
 
-#[derive(Clone, Copy)]
+/// Storage keys added after `DataKey` reached the 50 variants a contract
+/// type enum can hold.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKeyExt {
+    PendingFeeBasis,        // Gross fee basis waiting out its timelock
+    ResolvedChildren(u64),  // MarketId -> Resolved conditional markets depending on it
+    Referred(Address, u64), // User -> MarketId -> (Stake, predictions) credited to the referrer
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[contracttype]
 pub enum PredictionSide {
     Yes,
@@ -29,6 +85,7 @@ pub enum PredictionSide {
 pub struct Prediction {
     pub side: PredictionSide,
     pub amount: i128,
+    pub weighted: i128, // Stake after the market's early-bettor bonus
 }
 
 #[derive(Clone)]
@@ -38,11 +95,562 @@ pub struct Market {
     pub question: String,
     pub description: String,
     pub token: Address,         // Token used for betting
-    pub end_time: u64,          // Unix timestamp
+    pub close_time: u64,        // Unix timestamp, predictions close
+    pub resolve_after: u64,     // Unix timestamp, earliest resolution
+    pub resolve_deadline: u64,  // Unix timestamp, cancelled if still unresolved after it
     pub created_at: u64,        // Unix timestamp
     pub total_yes: i128,
     pub total_no: i128,
+    pub lp_yes: i128,           // Part of total_yes seeded by liquidity providers
+    pub lp_no: i128,            // Part of total_no seeded by liquidity providers
+    pub early_bonus: i128,      // Extra weight (basis points) for stakes placed at created_at
+    pub weighted_yes: i128,     // Sum of time-weighted Yes stakes
+    pub weighted_no: i128,      // Sum of time-weighted No stakes
     pub resolved: bool,
+    pub cancelled: bool,        // Explicitly invalidated, every stake is refundable
+    pub delisted: bool,         // Hidden by a moderator, see Delisting
+    pub commit_reveal: bool,    // Predictions are committed as hashes and revealed after close_time
+    pub metadata: MarketMetadata,
+    pub condition: MarketCondition,
+    pub fee_rate: i128,         // Platform fee rate (basis points) when the market was created
+    pub fee_basis: FeeBasis,    // Fee basis when the market was created
+}
+
+/// Ties a conditional market to the outcome of a parent market. If the parent
+/// resolves to a different outcome the conditional market is cancelled and
+/// every stake is refunded.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum MarketCondition {
+    None,
+    Parent(u64, PredictionSide), // Parent market id, required outcome
+}
+
+/// What a fee tier table is keyed on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum FeeTierKind {
+    Volume, // Cumulative stake a user has placed
+    Stake,  // Governance tokens a user has staked
+}
+
+/// A discounted fee rate for users at or above `threshold`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FeeTier {
+    pub threshold: i128,
+    pub fee_rate: i128, // Basis points
+}
+
+/// What the platform fee is charged on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum FeeBasis {
+    Gross,  // The whole payout, including the returned stake
+    Profit, // Only the winnings on top of the stake
+}
+
+/// When a market stops taking predictions and when it may be resolved.
+/// Betting usually closes before the event, with the outcome known later.
+#[derive(Clone)]
+#[contracttype]
+pub struct MarketSchedule {
+    pub close_time: u64,
+    pub resolve_after: u64,
+    pub resolve_deadline: Option<u64>, // None applies the admin's default resolution window
+}
+
+/// What a creator chooses for a new market.
+#[derive(Clone)]
+#[contracttype]
+pub struct MarketParams {
+    pub question: String,
+    pub description: String,
+    pub token: Address, // Token used for betting
+    pub schedule: MarketSchedule,
+    pub metadata: MarketMetadata,
+    pub early_bonus: i128, // Basis points, 0 disables
+    pub commit_reveal: bool,
+}
+
+/// What a creator chooses for a recurring market series.
+#[derive(Clone)]
+#[contracttype]
+pub struct SeriesParams {
+    pub question: String,
+    pub description: String,
+    pub token: Address,
+    pub duration: u64, // Seconds each round is open for predictions
+    pub cadence: u64,  // Seconds between the start of two rounds
+    pub metadata: MarketMetadata,
+}
+
+/// Bounds a user puts on a prediction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PredictionLimits {
+    pub min_payout: i128, // Smallest acceptable `quote_payout` for the stake, 0 disables
+    pub deadline: u32,    // Last ledger sequence the prediction may land in, 0 disables
+}
+
+/// A hidden prediction in a commit-reveal market. `deposit` is escrowed and
+/// must cover the revealed amount; the excess is returned at reveal.
+#[derive(Clone)]
+#[contracttype]
+pub struct Commitment {
+    pub hash: BytesN<32>,   // See compute_commitment
+    pub deposit: i128,
+    pub committed_at: u64,  // Unix timestamp, used for the early-bettor bonus
+    pub penalty_rate: i128, // Basis points kept if never revealed, fixed at commit
+}
+
+/// Attribution and rewards of a referrer in one token.
+#[derive(Clone, Default)]
+#[contracttype]
+pub struct ReferrerStats {
+    pub volume: i128,  // Total stake placed by referred users
+    pub referred: u32, // Predictions attributed to the referrer
+    pub earned: i128,  // Share of platform fees accrued
+    pub claimed: i128, // Part of earned already withdrawn
+}
+
+/// Balanced seed liquidity a provider put into a market. The position's
+/// shares are `yes + no`.
+#[derive(Clone)]
+#[contracttype]
+pub struct LpPosition {
+    pub yes: i128,
+    pub no: i128,
+    pub principal_withdrawn: bool,
+    pub fees_withdrawn: i128, // LP fee cut already paid out
+}
+
+/// Descriptive data shown by frontends, editable by the creator until the
+/// first prediction is placed.
+#[derive(Clone)]
+#[contracttype]
+pub struct MarketMetadata {
+    pub category: String,
+    pub tags: Vec<String>,
+    pub resolution_source: String, // URL of the source used to resolve the market
+    pub image_uri: String,
+}
+
+/// Highest early-bettor bonus a market can be created with (2x weight).
+const MAX_EARLY_BONUS: i128 = 10000;
+
+/// Length limits for `MarketMetadata` fields (in bytes).
+const MAX_CATEGORY_LEN: u32 = 32;
+const MAX_TAGS: u32 = 5;
+const MAX_TAG_LEN: u32 = 32;
+const MAX_URI_LEN: u32 = 256;
+
+/// Most market ids scanned by a single paged list view.
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Maximum length of a flag or delisting reason, or a resolution justification.
+const MAX_REASON_LEN: u32 = 256;
+
+/// Template for a question that is asked again on a fixed cadence. Anyone can
+/// roll the series to open the next round once the previous one has ended.
+/// The question is reused verbatim for every round.
+#[derive(Clone)]
+#[contracttype]
+pub struct MarketSeries {
+    pub creator: Address,
+    pub question: String,
+    pub description: String,
+    pub token: Address,
+    pub duration: u64,                  // Seconds each round is open for predictions
+    pub cadence: u64,                   // Seconds between the start of two rounds
+    pub metadata: MarketMetadata,
+    pub rounds: u32,                    // Number of markets opened so far
+    pub current_market_id: Option<u64>, // Market opened by the latest roll
+    pub last_rolled_at: u64,            // Unix timestamp
+}
+
+/// Payout accounting for a resolved market.
+#[derive(Clone, Default)]
+#[contracttype]
+pub struct MarketSettlement {
+    pub claimed_stake: i128,         // Winning stake that has already been paid out
+    pub distributed: i128,           // Gross pot share paid out so far (winnings + fees)
+    pub dust_swept: i128,            // Rounding remainder moved to platform fees
+    pub unclaimed_swept: i128,       // Collateral swept after the claim deadline
+    pub lp_fees: i128,               // Fee cut owed to the market's liquidity providers
+    pub insurance_paid: i128,        // Compensation paid from the insurance fund
+    pub resolved_at: u64,            // Unix timestamp
+    pub claim_deadline: Option<u64>, // Unix timestamp, None if claims never expire
+}
+
+/// Compensation owed to one user of a mis-resolved market.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Compensation {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// Who may create markets and series. The admin always can.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum CreationPolicy {
+    Open,      // Anyone meeting the reputation threshold, or allowlisted
+    Allowlist, // Allowlisted creators only
+    AdminOnly,
+}
+
+/// Why `create_market` rejected a creator.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CreationError {
+    AdminOnly = 1,
+    NotAllowlisted = 2,
+    InsufficientReputation = 3,
+}
+
+/// A user's report of a market, e.g. a misleading or abusive question.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MarketFlag {
+    pub reason: String,
+    pub flagged_at: u64, // Unix timestamp
+}
+
+/// A moderation decision hiding a market.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Delisting {
+    pub moderator: Address,
+    pub reason: String,
+    pub delisted_at: u64, // Unix timestamp
+}
+
+/// A platform fee increase waiting out its timelock.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingFeeRate {
+    pub rate: i128,        // Basis points
+    pub effective_at: u64, // Unix timestamp
+}
+
+/// A switch to the gross fee basis waiting out the fee timelock.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingFeeBasis {
+    pub basis: FeeBasis,
+    pub effective_at: u64, // Unix timestamp
+}
+
+/// Implied probability of each side, from the share of the pool it holds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MarketOdds {
+    pub yes: i128, // Basis points
+    pub no: i128,  // Basis points, yes + no = 10000
+}
+
+/// What a resolver attaches to an outcome so users can audit it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolutionEvidence {
+    pub reference: String,     // Content hash or URI of the evidence
+    pub justification: String, // Short explanation of the outcome
+}
+
+/// How and why a market was resolved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Resolution {
+    pub winning_side: PredictionSide,
+    pub resolver: Address, // Admin, resolver contract, or proposer of a multisig action
+    pub evidence: ResolutionEvidence,
+    pub resolved_at: u64, // Unix timestamp
+}
+
+/// A sensitive admin action that needs multisig approval.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AdminAction {
+    UpdateAdmin(Address),
+    SetPlatformFeeRate(i128),
+    ResolveMarket(u64, PredictionSide, ResolutionEvidence),
+    SetSigners(Vec<Address>, u32),           // New signers and threshold, empty disables multisig
+    SetResolver(Address),
+    SetTreasury(Address),
+    SweepUnclaimed(u64, Address),            // MarketId and recipient
+    InsurancePayout(u64, Vec<Compensation>), // MarketId and compensations
+    SetFeeBasis(FeeBasis),
+    SetCommitPenaltyRate(i128),
+    SetClaimWindow(u64),
+    SetResolutionWindow(u64),
+    SetModerator(Address, bool),
+    SetKeeperTipRate(i128),
+    SetLpFeeShare(i128),
+    SetReferralShare(i128),
+    SetInsuranceShare(i128),
+    SetInsuranceCap(i128),
+    SetFeeTiers(FeeTierKind, Vec<FeeTier>),
+    SetCreationPolicy(CreationPolicy),
+    SetAllowlisted(Address, bool),
+    SetMinCreatorReputation(u32),
+}
+
+/// An admin action waiting for signer approvals.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub signers_version: u32, // Signer set the approvals were collected from
+    pub expires_at: u64,      // Unix timestamp
+    pub executed: bool,
+}
+
+/// Outcome of a single market in a batch claim.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ClaimStatus {
+    Paid,
+    Refunded, // Market was cancelled, full stake returned
+    NoPrediction, // Never predicted or already claimed
+    NotResolved,
+    NotWinner,
+    ClaimWindowClosed,
+    Contested, // Resolution is under a dispute vote
+}
+
+impl ClaimStatus {
+    fn message(&self) -> &'static str {
+        match self {
+            ClaimStatus::Paid => "paid",
+            ClaimStatus::Refunded => "refunded",
+            ClaimStatus::NoPrediction => "no prediction found",
+            ClaimStatus::NotResolved => "market not resolved",
+            ClaimStatus::NotWinner => "not a winning prediction",
+            ClaimStatus::ClaimWindowClosed => "claim window closed",
+            ClaimStatus::Contested => "resolution contested",
+        }
+    }
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ClaimResult {
+    pub market_id: u64,
+    pub status: ClaimStatus,
+    pub amount: i128, // Net winnings or refund paid, 0 if skipped
+}
+
+/// Tokens owed to a user by a settled claim.
+struct Payout {
+    token: Address,
+    amount: i128,
+    status: ClaimStatus, // Paid or Refunded
+}
+
+/// What a claim would pay, before the fee is split up.
+struct ClaimQuote {
+    market: Market,
+    stake: i128,
+    pot_share: i128,     // Gross share of the pot, the stake itself on refunds
+    platform_fee: i128,
+    status: ClaimStatus, // Paid or Refunded
+}
+
+/// Upper bound on markets per `claim_many` call. Each claim touches a handful
+/// of ledger entries, so this keeps a batch well inside the per-transaction
+/// read/write entry limits.
+const MAX_CLAIM_BATCH: u32 = 20;
+
+/// Highest keeper tip the admin can configure (1%).
+const MAX_KEEPER_TIP_RATE: i128 = 100;
+
+/// Highest share of the platform fee the admin can route to referrers (50%).
+const MAX_REFERRAL_SHARE: i128 = 5000;
+
+/// Upper bound on recipients per `insurance_payout` call, for the same
+/// ledger entry limits as `MAX_CLAIM_BATCH`.
+const MAX_INSURANCE_RECIPIENTS: u32 = 20;
+
+/// Delay before a platform fee increase applies, so users can exit first.
+const FEE_RATE_TIMELOCK: u64 = 2 * 24 * 60 * 60; // 2 days
+
+/// Upper bound on multisig signers.
+const MAX_SIGNERS: u32 = 10;
+
+/// How long a proposal can collect approvals.
+const PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60; // 7 days
+
+/// Upper bound on entries per fee tier table.
+const MAX_FEE_TIERS: u32 = 10;
+
+/// How long staked tokens stay locked after the latest stake.
+const STAKE_LOCKUP: u64 = 7 * 24 * 60 * 60; // 7 days
+
+/// Default penalty on commitments that are never revealed (10%).
+const DEFAULT_COMMIT_PENALTY_RATE: i128 = 1000;
+
+/// Default time the admin has to resolve a market after `resolve_after` (30 days).
+const DEFAULT_RESOLUTION_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+/// Emitted when an unresolved market is invalidated after its resolution deadline.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketExpired {
+    #[topic]
+    pub market_id: u64,
+    pub resolve_deadline: u64,
+}
+
+/// The part of the fee treasury contract (contract/treasury) used to hand
+/// over platform fees.
+#[contractclient(name = "TreasuryClient")]
+pub trait TreasuryInterface {
+    /// Split tokens transferred to the treasury between its recipients
+    fn distribute(e: Env, token: Address) -> i128;
+}
+
+/// Emitted for every user compensated from the insurance fund.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePaid {
+    #[topic]
+    pub market_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the admin nominates a successor, who still has to accept.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferProposed {
+    #[topic]
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferred {
+    #[topic]
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+/// Emitted when a fee increase is scheduled behind the timelock.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRateIncreaseScheduled {
+    pub rate: i128,
+    pub effective_at: u64,
+}
+
+/// Emitted when the platform fee rate changes immediately (decreases).
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRateChanged {
+    pub rate: i128,
+}
+
+/// Emitted when a switch to the gross fee basis is scheduled behind the timelock.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeBasisChangeScheduled {
+    pub basis: FeeBasis,
+    pub effective_at: u64,
+}
+
+/// Emitted when the fee basis changes immediately (back to profit).
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeBasisChanged {
+    pub basis: FeeBasis,
+}
+
+/// Emitted when a signer proposes a sensitive admin action.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionProposed {
+    #[topic]
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub action: AdminAction,
+    pub expires_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionApproved {
+    #[topic]
+    pub proposal_id: u64,
+    pub signer: Address,
+    pub approvals: u32,
+}
+
+/// Emitted when a proposal reaches its threshold and the action is applied.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionExecuted {
+    #[topic]
+    pub proposal_id: u64,
+}
+
+/// Emitted when a market is resolved.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketResolved {
+    #[topic]
+    pub market_id: u64,
+    pub winning_side: PredictionSide,
+    pub resolver: Address,
+    pub evidence: ResolutionEvidence,
+}
+
+/// Emitted when the resolver puts a market's resolution to a new vote.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionContested {
+    #[topic]
+    pub market_id: u64,
+    pub resolver: Address,
+    pub winning_side: PredictionSide,
+}
+
+/// Emitted when a user flags a market.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketFlagged {
+    #[topic]
+    pub market_id: u64,
+    #[topic]
+    pub user: Address,
+    pub reason: String,
+    pub flags: u32,
+}
+
+/// Emitted when a moderator delists a market.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketDelisted {
+    #[topic]
+    pub market_id: u64,
+    pub moderator: Address,
+    pub reason: String,
+    pub refunded: bool,
+}
+
+/// Emitted when leftover collateral is swept out of a market after its claim deadline.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnclaimedSwept {
+    #[topic]
+    pub market_id: u64,
+    pub to: Address,
+    pub amount: i128,
 }
 
 fn has_admin(e: &Env) -> bool {
@@ -93,169 +701,2785 @@ fn set_user_prediction(e: &Env, user: &Address, market_id: u64, prediction: &Pre
         .set(&DataKey::UserPredictions(user.clone(), market_id), prediction);
 }
 
+/// A scheduled fee increase, once its timelock has passed, is read as the
+/// current rate.
 fn get_platform_fee_rate(e: &Env) -> i128 {
+    if let Some(pending) = get_pending_fee_rate(e) {
+        if e.ledger().timestamp() >= pending.effective_at {
+            return pending.rate;
+        }
+    }
+
     e.storage()
         .instance()
         .get(&DataKey::PlatformFeeRate)
         .unwrap_or(200) // Default 2% (200 basis points)
 }
 
-fn set_platform_fee_rate(e: &Env, rate: i128) {
-    if rate < 0 || rate > 10000 {
+fn validate_fee_rate(rate: i128) {
+    if !(0..=10000).contains(&rate) {
         panic!("fee rate must be between 0 and 10000 (0-100%)");
     }
+}
+
+fn set_platform_fee_rate(e: &Env, rate: i128) {
+    validate_fee_rate(rate);
     e.storage().instance().set(&DataKey::PlatformFeeRate, &rate);
 }
 
-fn transfer_tokens(e: &Env, token: &Address, from: &Address, to: &Address, amount: i128) {
-    token::Client::new(e, token).transfer(from, to, &amount);
+fn get_pending_fee_rate(e: &Env) -> Option<PendingFeeRate> {
+    e.storage().instance().get(&DataKey::PendingFeeRate)
 }
 
-fn get_winning_side(e: &Env, market_id: u64) -> Option<PredictionSide> {
-    e.storage()
-        .persistent()
-        .get(&DataKey::WinningSide(market_id))
+/// Change the platform fee rate. Decreases apply immediately and cancel a
+/// scheduled increase, increases only apply after `FEE_RATE_TIMELOCK`.
+fn change_platform_fee_rate(e: &Env, rate: i128) {
+    validate_fee_rate(rate);
+
+    // Settle a matured increase before replacing it
+    let current = get_platform_fee_rate(e);
+    set_platform_fee_rate(e, current);
+    e.storage().instance().remove(&DataKey::PendingFeeRate);
+
+    if rate <= current {
+        set_platform_fee_rate(e, rate);
+        FeeRateChanged { rate }.publish(e);
+    } else {
+        let pending = PendingFeeRate {
+            rate,
+            effective_at: e.ledger().timestamp() + FEE_RATE_TIMELOCK,
+        };
+        e.storage().instance().set(&DataKey::PendingFeeRate, &pending);
+
+        FeeRateIncreaseScheduled {
+            rate,
+            effective_at: pending.effective_at,
+        }
+        .publish(e);
+    }
 }
 
-fn set_winning_side(e: &Env, market_id: u64, side: PredictionSide) {
+/// Nominate a new admin, who has to accept before the role moves.
+fn propose_admin(e: &Env, new_admin: &Address) {
+    e.storage().instance().set(&DataKey::PendingAdmin, new_admin);
+
+    AdminTransferProposed {
+        admin: get_admin(e),
+        new_admin: new_admin.clone(),
+    }
+    .publish(e);
+}
+
+fn get_pending_admin(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+fn get_fee_tiers(e: &Env, kind: FeeTierKind) -> Vec<FeeTier> {
     e.storage()
-        .persistent()
-        .set(&DataKey::WinningSide(market_id), &side);
+        .instance()
+        .get(&DataKey::FeeTiers(kind))
+        .unwrap_or(Vec::new(e))
 }
 
-// Contract metadata
-contractmeta!(
-    key = "Description",
-    val = "Decentralized Prediction Market - Bet on future events with decentralized resolution"
-);
+fn set_fee_tiers(e: &Env, kind: FeeTierKind, tiers: &Vec<FeeTier>) {
+    if tiers.len() > MAX_FEE_TIERS {
+        panic!("too many fee tiers");
+    }
 
-#[contract]
-pub struct PredictionMarket;
+    let mut last_threshold = 0;
+    for tier in tiers.iter() {
+        if tier.threshold <= last_threshold {
+            panic!("tier thresholds must be positive and increasing");
+        }
+        validate_fee_rate(tier.fee_rate);
+        last_threshold = tier.threshold;
+    }
 
-#[contractimpl]
-impl PredictionMarket {
-    /// Initialize the contract with an admin address
-    pub fn initialize(e: Env, admin: Address) {
-        if has_admin(&e) {
-            panic!("already initialized");
+    e.storage().instance().set(&DataKey::FeeTiers(kind), tiers);
+}
+
+/// Rate of the highest tier reached by `value`, if any.
+fn tier_fee_rate(e: &Env, kind: FeeTierKind, value: i128) -> Option<i128> {
+    let mut rate = None;
+    for tier in get_fee_tiers(e, kind).iter() {
+        if value < tier.threshold {
+            break;
         }
-        set_admin(&e, &admin);
-        set_market_count(&e, 0);
-        set_platform_fee_rate(&e, 200); // 2% default fee
+        rate = Some(tier.fee_rate);
     }
+    rate
+}
 
-    /// Create a new prediction market
-    pub fn create_market(
-        e: Env,
-        creator: Address,
-        question: String,
-        description: String,
-        token: Address,
-        end_time: u64,
-    ) -> u64 {
-        creator.require_auth();
+fn get_user_volume(e: &Env, user: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::UserVolume(user.clone()))
+        .unwrap_or(0)
+}
 
-        let current_time = e.ledger().timestamp();
-        if end_time <= current_time {
-            panic!("end_time must be in the future");
-        }
-
-        let market_id = get_market_count(&e) + 1;
-
-        let market = Market {
-            creator: creator.clone(),
-            question: question.clone(),
-            description: description.clone(),
-            token: token.clone(),
-            end_time,
-            created_at: current_time,
-            total_yes: 0,
-            total_no: 0,
-            resolved: false,
-        };
+fn set_user_volume(e: &Env, user: &Address, volume: i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::UserVolume(user.clone()), &volume);
+}
 
-        set_market(&e, market_id, &market);
-        set_market_count(&e, market_id);
+fn get_staked_balance(e: &Env, user: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::StakedBalance(user.clone()))
+        .unwrap_or(0)
+}
 
-        market_id
-    }
+fn set_staked_balance(e: &Env, user: &Address, amount: i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::StakedBalance(user.clone()), &amount);
+}
 
-    /// Place a prediction on a market
-    pub fn predict(
-        e: Env,
-        user: Address,
-        market_id: u64,
-        side: PredictionSide,
-        amount: i128,
-    ) {
-        user.require_auth();
+fn get_staking_token(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::StakingToken)
+}
 
-        if amount <= 0 {
-            panic!("amount must be positive");
-        }
+/// Fee rate charged to a user: the best of the flat platform rate and the
+/// tiers reached by their volume and staked balance.
+fn get_effective_fee_rate(e: &Env, user: &Address) -> i128 {
+    tiered_fee_rate(e, user, get_platform_fee_rate(e))
+}
 
-        let mut market = get_market(&e, market_id);
+/// The best of `rate` and the tiers reached by a user's volume and staked
+/// balance.
+fn tiered_fee_rate(e: &Env, user: &Address, mut rate: i128) -> i128 {
+    let volume_rate = tier_fee_rate(e, FeeTierKind::Volume, get_user_volume(e, user));
+    let stake_rate = tier_fee_rate(e, FeeTierKind::Stake, get_staked_balance(e, user));
+    for tier_rate in [volume_rate, stake_rate].into_iter().flatten() {
+        rate = rate.min(tier_rate);
+    }
+    rate
+}
 
-        if market.resolved {
-            panic!("market already resolved");
+fn get_fee_basis(e: &Env) -> FeeBasis {
+    if let Some(pending) = get_pending_fee_basis(e) {
+        if e.ledger().timestamp() >= pending.effective_at {
+            return pending.basis;
         }
+    }
 
-        let current_time = e.ledger().timestamp();
-        if current_time >= market.end_time {
-            panic!("market has ended");
-        }
+    e.storage()
+        .instance()
+        .get(&DataKey::FeeBasis)
+        .unwrap_or(FeeBasis::Profit)
+}
+
+fn get_pending_fee_basis(e: &Env) -> Option<PendingFeeBasis> {
+    e.storage().instance().get(&DataKeyExt::PendingFeeBasis)
+}
+
+/// Change what the platform fee is charged on. Charging the gross payout
+/// raises fees, so like a rate increase it only applies after
+/// `FEE_RATE_TIMELOCK`; going back to profit applies immediately.
+fn change_fee_basis(e: &Env, basis: FeeBasis) {
+    // Settle a matured switch before replacing it
+    let current = get_fee_basis(e);
+    e.storage().instance().set(&DataKey::FeeBasis, &current);
+    e.storage().instance().remove(&DataKeyExt::PendingFeeBasis);
+
+    if basis == current || basis == FeeBasis::Profit {
+        e.storage().instance().set(&DataKey::FeeBasis, &basis);
+        FeeBasisChanged { basis }.publish(e);
+    } else {
+        let pending = PendingFeeBasis {
+            basis,
+            effective_at: e.ledger().timestamp() + FEE_RATE_TIMELOCK,
+        };
+        e.storage().instance().set(&DataKeyExt::PendingFeeBasis, &pending);
+
+        FeeBasisChangeScheduled {
+            basis,
+            effective_at: pending.effective_at,
+        }
+        .publish(e);
+    }
+}
+
+fn get_keeper_tip_rate(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::KeeperTipRate)
+        .unwrap_or(0) // Default: no tip
+}
+
+fn set_keeper_tip_rate(e: &Env, rate: i128) {
+    if !(0..=MAX_KEEPER_TIP_RATE).contains(&rate) {
+        panic!("keeper tip rate must be between 0 and 100 (0-1%)");
+    }
+    e.storage().instance().set(&DataKey::KeeperTipRate, &rate);
+}
+
+fn validate_metadata(metadata: &MarketMetadata) {
+    if metadata.category.len() > MAX_CATEGORY_LEN {
+        panic!("category too long");
+    }
+    if metadata.tags.len() > MAX_TAGS {
+        panic!("too many tags");
+    }
+    for tag in metadata.tags.iter() {
+        if tag.len() > MAX_TAG_LEN {
+            panic!("tag too long");
+        }
+    }
+    if metadata.resolution_source.len() > MAX_URI_LEN {
+        panic!("resolution source too long");
+    }
+    if metadata.image_uri.len() > MAX_URI_LEN {
+        panic!("image uri too long");
+    }
+}
+
+fn get_lp_position(e: &Env, provider: &Address, market_id: u64) -> Option<LpPosition> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::LpPositions(provider.clone(), market_id))
+}
+
+fn set_lp_position(e: &Env, provider: &Address, market_id: u64, position: &LpPosition) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::LpPositions(provider.clone(), market_id), position);
+}
+
+fn get_lp_fee_share(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::LpFeeShare)
+        .unwrap_or(0) // Default: LPs get no fee cut
+}
+
+fn set_lp_fee_share(e: &Env, share: i128) {
+    if !(0..=10000).contains(&share) {
+        panic!("lp fee share must be between 0 and 10000 (0-100%)");
+    }
+    e.storage().instance().set(&DataKey::LpFeeShare, &share);
+}
+
+fn get_referral_share(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::ReferralShare)
+        .unwrap_or(0) // Default: referrals earn nothing
+}
+
+fn set_referral_share(e: &Env, share: i128) {
+    if !(0..=MAX_REFERRAL_SHARE).contains(&share) {
+        panic!("referral share must be between 0 and 5000 (0-50%)");
+    }
+    e.storage().instance().set(&DataKey::ReferralShare, &share);
+}
+
+fn get_referrer(e: &Env, user: &Address, market_id: u64) -> Option<Address> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Referrals(user.clone(), market_id))
+}
+
+fn get_referrer_stats(e: &Env, referrer: &Address, token: &Address) -> ReferrerStats {
+    e.storage()
+        .persistent()
+        .get(&DataKey::ReferrerStats(referrer.clone(), token.clone()))
+        .unwrap_or_default()
+}
+
+fn set_referrer_stats(e: &Env, referrer: &Address, token: &Address, stats: &ReferrerStats) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::ReferrerStats(referrer.clone(), token.clone()), stats);
+}
+
+/// Attribute a prediction to a referrer. The first referrer of a user on a
+/// market keeps the attribution for later top-ups.
+fn record_referral(
+    e: &Env,
+    market: &Market,
+    market_id: u64,
+    user: &Address,
+    referrer: &Address,
+    amount: i128,
+) {
+    if referrer == user {
+        panic!("cannot refer yourself");
+    }
+
+    let referrer = match get_referrer(e, user, market_id) {
+        Some(existing) => existing,
+        None => {
+            e.storage()
+                .persistent()
+                .set(&DataKey::Referrals(user.clone(), market_id), referrer);
+            referrer.clone()
+        }
+    };
+
+    let mut stats = get_referrer_stats(e, &referrer, &market.token);
+    stats.volume += amount;
+    stats.referred += 1;
+    set_referrer_stats(e, &referrer, &market.token, &stats);
+
+    let key = DataKeyExt::Referred(user.clone(), market_id);
+    let (stake, predictions): (i128, u32) = e.storage().persistent().get(&key).unwrap_or((0, 0));
+    e.storage()
+        .persistent()
+        .set(&key, &(stake + amount, predictions + 1));
+}
+
+/// Drop a user's referral on a market. On a refund the referrer's stats no
+/// longer count what the user had staked.
+fn clear_referral(e: &Env, market: &Market, market_id: u64, user: &Address, refunded: bool) {
+    let key = DataKeyExt::Referred(user.clone(), market_id);
+    if refunded {
+        if let Some(referrer) = get_referrer(e, user, market_id) {
+            let (stake, predictions): (i128, u32) =
+                e.storage().persistent().get(&key).unwrap_or((0, 0));
+            let mut stats = get_referrer_stats(e, &referrer, &market.token);
+            stats.volume -= stake;
+            stats.referred -= predictions;
+            set_referrer_stats(e, &referrer, &market.token, &stats);
+        }
+    }
+
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Referrals(user.clone(), market_id));
+    e.storage().persistent().remove(&key);
+}
+
+fn get_commitment(e: &Env, user: &Address, market_id: u64) -> Option<Commitment> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Commitments(user.clone(), market_id))
+}
+
+fn set_commitment(e: &Env, user: &Address, market_id: u64, commitment: &Commitment) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Commitments(user.clone(), market_id), commitment);
+}
+
+fn get_commitment_count(e: &Env, market_id: u64) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::CommitmentCount(market_id))
+        .unwrap_or(0)
+}
+
+fn remove_commitment(e: &Env, user: &Address, market_id: u64) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Commitments(user.clone(), market_id));
+}
+
+fn get_commit_penalty_rate(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::CommitPenaltyRate)
+        .unwrap_or(DEFAULT_COMMIT_PENALTY_RATE)
+}
+
+fn set_commit_penalty_rate(e: &Env, rate: i128) {
+    if !(0..=10000).contains(&rate) {
+        panic!("penalty rate must be between 0 and 10000 (0-100%)");
+    }
+    e.storage().instance().set(&DataKey::CommitPenaltyRate, &rate);
+}
+
+/// sha256 over the contract and user addresses (XDR), the market id (8 bytes,
+/// big endian), the side (one byte, 0 = Yes, 1 = No), the amount (16 bytes,
+/// big endian) and the salt. Binding the hash to the contract, user and market
+/// keeps anyone from copying a commitment and revealing it with the victim's salt.
+fn compute_commitment(
+    e: &Env,
+    user: &Address,
+    market_id: u64,
+    side: PredictionSide,
+    amount: i128,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let mut preimage = e.current_contract_address().to_xdr(e);
+    preimage.append(&user.clone().to_xdr(e));
+    preimage.extend_from_array(&market_id.to_be_bytes());
+    preimage.push_back(match side {
+        PredictionSide::Yes => 0,
+        PredictionSide::No => 1,
+    });
+    preimage.extend_from_array(&amount.to_be_bytes());
+    preimage.append(&Bytes::from(salt.clone()));
+    e.crypto().sha256(&preimage).into()
+}
+
+fn transfer_tokens(e: &Env, token: &Address, from: &Address, to: &Address, amount: i128) {
+    token::Client::new(e, token).transfer(from, to, &amount);
+}
+
+fn add_market(e: &Env, market: &Market) -> u64 {
+    let market_id = get_market_count(e) + 1;
+
+    set_market(e, market_id, market);
+    set_market_count(e, market_id);
+
+    market_id
+}
+
+/// Validate the creator's choices and store a new market.
+fn new_market(
+    e: &Env,
+    creator: &Address,
+    params: MarketParams,
+    condition: MarketCondition,
+) -> u64 {
+    validate_schedule(e, &params.schedule);
+    validate_metadata(&params.metadata);
+    validate_early_bonus(params.early_bonus);
+
+    if params.commit_reveal && params.schedule.resolve_after == params.schedule.close_time {
+        panic!("commit-reveal markets need resolve_after after close_time");
+    }
+
+    let market = Market {
+        creator: creator.clone(),
+        question: params.question,
+        description: params.description,
+        token: params.token,
+        close_time: params.schedule.close_time,
+        resolve_after: params.schedule.resolve_after,
+        resolve_deadline: resolve_deadline(e, &params.schedule),
+        created_at: e.ledger().timestamp(),
+        total_yes: 0,
+        total_no: 0,
+        lp_yes: 0,
+        lp_no: 0,
+        early_bonus: params.early_bonus,
+        weighted_yes: 0,
+        weighted_no: 0,
+        resolved: false,
+        cancelled: false,
+        delisted: false,
+        commit_reveal: params.commit_reveal,
+        metadata: params.metadata,
+        condition,
+        fee_rate: get_platform_fee_rate(e),
+        fee_basis: get_fee_basis(e),
+    };
+
+    add_market(e, &market)
+}
+
+/// An unresolved market is cancelled once its resolution deadline passes,
+/// even before anyone calls `expire_market`. A conditional market is also
+/// cancelled once its parent resolves to a different outcome, or once the
+/// parent itself is cancelled.
+fn is_cancelled(e: &Env, market: &Market) -> bool {
+    if market.resolved {
+        return false;
+    }
+
+    if market.cancelled || e.ledger().timestamp() > market.resolve_deadline {
+        return true;
+    }
+
+    match market.condition {
+        MarketCondition::Parent(parent_id, outcome) => match get_winning_side(e, parent_id) {
+            Some(side) => side != outcome,
+            None => is_cancelled(e, &get_market(e, parent_id)),
+        },
+        MarketCondition::None => false,
+    }
+}
+
+fn get_series(e: &Env, series_id: u64) -> MarketSeries {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Series(series_id))
+        .unwrap()
+}
+
+fn set_series(e: &Env, series_id: u64, series: &MarketSeries) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Series(series_id), series);
+}
+
+fn get_series_count(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DataKey::SeriesCount)
+        .unwrap_or(0)
+}
+
+fn set_series_count(e: &Env, count: u64) {
+    e.storage().instance().set(&DataKey::SeriesCount, &count);
+}
+
+fn get_settlement(e: &Env, market_id: u64) -> MarketSettlement {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Settlement(market_id))
+        .unwrap_or_default()
+}
+
+fn set_settlement(e: &Env, market_id: u64, settlement: &MarketSettlement) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Settlement(market_id), settlement);
+}
+
+fn get_collected_fees(e: &Env, token: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::CollectedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+fn add_collected_fees(e: &Env, token: &Address, amount: i128) {
+    let total = get_collected_fees(e, token) + amount;
+    e.storage()
+        .persistent()
+        .set(&DataKey::CollectedFees(token.clone()), &total);
+}
+
+fn get_insurance_share(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::InsuranceShare)
+        .unwrap_or(0) // Default: no insurance fund
+}
+
+fn set_insurance_share(e: &Env, share: i128) {
+    if !(0..=10000).contains(&share) {
+        panic!("insurance share must be between 0 and 10000 (0-100%)");
+    }
+    e.storage().instance().set(&DataKey::InsuranceShare, &share);
+}
+
+fn get_insurance_cap(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::InsuranceCap)
+        .unwrap_or(10000) // Default: up to the market's whole pool
+}
+
+fn set_insurance_cap(e: &Env, cap: i128) {
+    if !(0..=10000).contains(&cap) {
+        panic!("insurance cap must be between 0 and 10000 (0-100%)");
+    }
+    e.storage().instance().set(&DataKey::InsuranceCap, &cap);
+}
+
+fn get_insurance_fund(e: &Env, token: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::InsuranceFund(token.clone()))
+        .unwrap_or(0)
+}
+
+fn set_insurance_fund(e: &Env, token: &Address, amount: i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::InsuranceFund(token.clone()), &amount);
+}
+
+fn get_signers(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&DataKey::Signers)
+        .unwrap_or(Vec::new(e))
+}
+
+fn get_threshold(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Threshold).unwrap_or(0)
+}
+
+fn get_signers_version(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::SignersVersion)
+        .unwrap_or(0)
+}
+
+fn multisig_enabled(e: &Env) -> bool {
+    !get_signers(e).is_empty()
+}
+
+/// Replace the signer set. Pending proposals are voided, so approvals of
+/// removed signers can't be replayed.
+fn set_signers(e: &Env, signers: &Vec<Address>, threshold: u32) {
+    if signers.len() > MAX_SIGNERS {
+        panic!("too many signers");
+    }
+
+    if signers.is_empty() {
+        if threshold != 0 {
+            panic!("threshold must be 0 without signers");
+        }
+    } else if threshold == 0 || threshold > signers.len() {
+        panic!("threshold must be between 1 and the number of signers");
+    }
+
+    for (i, signer) in signers.iter().enumerate() {
+        if signers.first_index_of(&signer) != Some(i as u32) {
+            panic!("duplicate signer");
+        }
+    }
+
+    e.storage().instance().set(&DataKey::Signers, signers);
+    e.storage().instance().set(&DataKey::Threshold, &threshold);
+    e.storage()
+        .instance()
+        .set(&DataKey::SignersVersion, &(get_signers_version(e) + 1));
+}
+
+fn get_proposal(e: &Env, proposal_id: u64) -> Proposal {
+    match e.storage().persistent().get(&DataKey::Proposals(proposal_id)) {
+        Some(proposal) => proposal,
+        None => panic!("proposal not found"),
+    }
+}
+
+fn set_proposal(e: &Env, proposal: &Proposal) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Proposals(proposal.id), proposal);
+}
+
+fn get_proposal_count(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DataKey::ProposalCount)
+        .unwrap_or(0)
+}
+
+fn require_signer(e: &Env, signer: &Address) {
+    signer.require_auth();
+
+    if !get_signers(e).contains(signer) {
+        panic!("not a signer");
+    }
+}
+
+fn set_resolver(e: &Env, resolver: &Address) {
+    e.storage().instance().set(&DataKey::Resolver, resolver);
+}
+
+fn set_treasury(e: &Env, treasury: &Address) {
+    e.storage().instance().set(&DataKey::Treasury, treasury);
+}
+
+/// Move a market's collateral left unclaimed after the claim deadline.
+fn sweep_unclaimed(e: &Env, market_id: u64, to: &Address) -> i128 {
+    let market = get_market(e, market_id);
+
+    if !market.resolved {
+        panic!("market not resolved");
+    }
+
+    if is_contested(e, market_id) {
+        panic!("resolution contested");
+    }
+
+    let mut settlement = get_settlement(e, market_id);
+    if !claim_deadline_passed(e, &settlement) {
+        panic!("claim window still open");
+    }
+
+    let amount = remaining_collateral(&market, &settlement);
+    if amount > 0 {
+        settlement.unclaimed_swept += amount;
+        set_settlement(e, market_id, &settlement);
+
+        transfer_tokens(e, &market.token, &e.current_contract_address(), to, amount);
+    }
+
+    UnclaimedSwept {
+        market_id,
+        to: to.clone(),
+        amount,
+    }
+    .publish(e);
+
+    amount
+}
+
+/// Compensate users of a mis-resolved market out of the insurance fund.
+fn insurance_payout(e: &Env, market_id: u64, recipients: &Vec<Compensation>) -> i128 {
+    if recipients.len() > MAX_INSURANCE_RECIPIENTS {
+        panic!("too many recipients");
+    }
+
+    let market = get_market(e, market_id);
+
+    if !market.resolved {
+        panic!("market not resolved");
+    }
+
+    if is_contested(e, market_id) {
+        panic!("resolution contested");
+    }
+
+    let mut total = 0;
+    for compensation in recipients.iter() {
+        if compensation.amount <= 0 {
+            panic!("amount must be positive");
+        }
+        total += compensation.amount;
+    }
+
+    let mut settlement = get_settlement(e, market_id);
+    let cap = ((market.total_yes + market.total_no) * get_insurance_cap(e)) / 10000;
+    if settlement.insurance_paid + total > cap {
+        panic!("insurance cap exceeded");
+    }
+
+    let fund = get_insurance_fund(e, &market.token);
+    if total > fund {
+        panic!("insufficient insurance fund");
+    }
+
+    settlement.insurance_paid += total;
+    set_settlement(e, market_id, &settlement);
+    set_insurance_fund(e, &market.token, fund - total);
+
+    for compensation in recipients.iter() {
+        transfer_tokens(
+            e,
+            &market.token,
+            &e.current_contract_address(),
+            &compensation.recipient,
+            compensation.amount,
+        );
+
+        InsurancePaid {
+            market_id,
+            recipient: compensation.recipient,
+            amount: compensation.amount,
+        }
+        .publish(e);
+    }
+
+    total
+}
+
+/// Apply an approved action.
+fn execute_action(e: &Env, proposal: &Proposal) {
+    match &proposal.action {
+        AdminAction::UpdateAdmin(new_admin) => propose_admin(e, new_admin),
+        AdminAction::SetPlatformFeeRate(rate) => change_platform_fee_rate(e, *rate),
+        AdminAction::SetFeeBasis(basis) => change_fee_basis(e, *basis),
+        AdminAction::ResolveMarket(market_id, winning_side, evidence) => {
+            resolve(e, *market_id, *winning_side, &proposal.proposer, evidence)
+        }
+        AdminAction::SetSigners(signers, threshold) => set_signers(e, signers, *threshold),
+        AdminAction::SetResolver(resolver) => set_resolver(e, resolver),
+        AdminAction::SetTreasury(treasury) => set_treasury(e, treasury),
+        AdminAction::SweepUnclaimed(market_id, to) => {
+            sweep_unclaimed(e, *market_id, to);
+        }
+        AdminAction::InsurancePayout(market_id, recipients) => {
+            insurance_payout(e, *market_id, recipients);
+        }
+        AdminAction::SetCommitPenaltyRate(rate) => set_commit_penalty_rate(e, *rate),
+        AdminAction::SetClaimWindow(window) => set_claim_window(e, *window),
+        AdminAction::SetResolutionWindow(window) => set_resolution_window(e, *window),
+        AdminAction::SetModerator(moderator, enabled) => set_moderator(e, moderator, *enabled),
+        AdminAction::SetKeeperTipRate(rate) => set_keeper_tip_rate(e, *rate),
+        AdminAction::SetLpFeeShare(share) => set_lp_fee_share(e, *share),
+        AdminAction::SetReferralShare(share) => set_referral_share(e, *share),
+        AdminAction::SetInsuranceShare(share) => set_insurance_share(e, *share),
+        AdminAction::SetInsuranceCap(cap) => set_insurance_cap(e, *cap),
+        AdminAction::SetFeeTiers(kind, tiers) => set_fee_tiers(e, *kind, tiers),
+        AdminAction::SetCreationPolicy(policy) => set_creation_policy(e, *policy),
+        AdminAction::SetAllowlisted(creator, allowed) => set_allowlisted(e, creator, *allowed),
+        AdminAction::SetMinCreatorReputation(min) => set_min_creator_reputation(e, *min),
+    }
+}
+
+/// Record a signer's approval and execute the action at the threshold.
+fn approve(e: &Env, proposal: &mut Proposal, signer: &Address) {
+    proposal.approvals.push_back(signer.clone());
+
+    ActionApproved {
+        proposal_id: proposal.id,
+        signer: signer.clone(),
+        approvals: proposal.approvals.len(),
+    }
+    .publish(e);
+
+    if proposal.approvals.len() >= get_threshold(e) {
+        proposal.executed = true;
+        set_proposal(e, proposal);
+        execute_action(e, proposal);
+
+        ActionExecuted {
+            proposal_id: proposal.id,
+        }
+        .publish(e);
+    } else {
+        set_proposal(e, proposal);
+    }
+}
+
+fn get_creation_policy(e: &Env) -> CreationPolicy {
+    e.storage()
+        .instance()
+        .get(&DataKey::CreationPolicy)
+        .unwrap_or(CreationPolicy::Open)
+}
+
+fn set_creation_policy(e: &Env, policy: CreationPolicy) {
+    e.storage().instance().set(&DataKey::CreationPolicy, &policy);
+}
+
+fn set_allowlisted(e: &Env, creator: &Address, allowed: bool) {
+    if allowed {
+        e.storage()
+            .persistent()
+            .set(&DataKey::Allowlisted(creator.clone()), &true);
+    } else {
+        e.storage()
+            .persistent()
+            .remove(&DataKey::Allowlisted(creator.clone()));
+    }
+}
+
+fn is_allowlisted(e: &Env, creator: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Allowlisted(creator.clone()))
+        .unwrap_or(false)
+}
+
+fn get_creator_reputation(e: &Env, creator: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::CreatorReputation(creator.clone()))
+        .unwrap_or(0)
+}
+
+fn get_min_creator_reputation(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::MinCreatorReputation)
+        .unwrap_or(0)
+}
+
+fn set_min_creator_reputation(e: &Env, min_reputation: u32) {
+    e.storage()
+        .instance()
+        .set(&DataKey::MinCreatorReputation, &min_reputation);
+}
+
+fn set_moderator(e: &Env, moderator: &Address, enabled: bool) {
+    if enabled {
+        e.storage()
+            .persistent()
+            .set(&DataKey::Moderators(moderator.clone()), &true);
+    } else {
+        e.storage()
+            .persistent()
+            .remove(&DataKey::Moderators(moderator.clone()));
+    }
+}
+
+fn is_moderator(e: &Env, moderator: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Moderators(moderator.clone()))
+        .unwrap_or(false)
+}
+
+fn get_flag_count(e: &Env, market_id: u64) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::FlagCount(market_id))
+        .unwrap_or(0)
+}
+
+/// Enforce the creation policy for a new market or series.
+fn check_creation_policy(e: &Env, creator: &Address) {
+    if *creator == get_admin(e) {
+        return;
+    }
+
+    match get_creation_policy(e) {
+        CreationPolicy::AdminOnly => panic_with_error!(e, CreationError::AdminOnly),
+        CreationPolicy::Allowlist => {
+            if !is_allowlisted(e, creator) {
+                panic_with_error!(e, CreationError::NotAllowlisted);
+            }
+        }
+        CreationPolicy::Open => {
+            if !is_allowlisted(e, creator)
+                && get_creator_reputation(e, creator) < get_min_creator_reputation(e)
+            {
+                panic_with_error!(e, CreationError::InsufficientReputation);
+            }
+        }
+    }
+}
+
+fn get_resolver(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Resolver)
+}
+
+fn is_contested(e: &Env, market_id: u64) -> bool {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Contested(market_id))
+        .unwrap_or(false)
+}
+
+fn get_resolved_children(e: &Env, market_id: u64) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKeyExt::ResolvedChildren(market_id))
+        .unwrap_or(0)
+}
+
+fn get_treasury(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Treasury)
+}
+
+/// Hand a token's collected fees over to the treasury, if one is set.
+fn forward_fees(e: &Env, token: &Address) -> i128 {
+    let treasury = match get_treasury(e) {
+        Some(treasury) => treasury,
+        None => return 0,
+    };
+
+    let fees = get_collected_fees(e, token);
+    if fees <= 0 {
+        return 0;
+    }
+
+    e.storage()
+        .persistent()
+        .set(&DataKey::CollectedFees(token.clone()), &0i128);
+    transfer_tokens(e, token, &e.current_contract_address(), &treasury, fees);
+    // A failing treasury mustn't block claims. The fees stay in its balance
+    // and are picked up by the next successful `distribute`.
+    let _ = TreasuryClient::new(e, &treasury).try_distribute(token);
+
+    fees
+}
+
+fn get_claim_window(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DataKey::ClaimWindow)
+        .unwrap_or(0) // Default: claims never expire
+}
+
+fn set_claim_window(e: &Env, window: u64) {
+    e.storage().instance().set(&DataKey::ClaimWindow, &window);
+}
+
+fn claim_deadline_passed(e: &Env, settlement: &MarketSettlement) -> bool {
+    match settlement.claim_deadline {
+        Some(deadline) => e.ledger().timestamp() > deadline,
+        None => false,
+    }
+}
+
+fn remaining_collateral(market: &Market, settlement: &MarketSettlement) -> i128 {
+    market.total_yes + market.total_no
+        - settlement.distributed
+        - settlement.dust_swept
+        - settlement.unclaimed_swept
+}
+
+fn validate_schedule(e: &Env, schedule: &MarketSchedule) {
+    if schedule.close_time <= e.ledger().timestamp() {
+        panic!("close_time must be in the future");
+    }
+    if schedule.resolve_after < schedule.close_time {
+        panic!("resolve_after must not be before close_time");
+    }
+    if let Some(deadline) = schedule.resolve_deadline {
+        if deadline <= schedule.resolve_after {
+            panic!("resolve_deadline must be after resolve_after");
+        }
+    }
+}
+
+fn get_resolution_window(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DataKey::ResolutionWindow)
+        .unwrap_or(DEFAULT_RESOLUTION_WINDOW)
+}
+
+fn set_resolution_window(e: &Env, window: u64) {
+    if window == 0 {
+        panic!("resolution window must be positive");
+    }
+    e.storage().instance().set(&DataKey::ResolutionWindow, &window);
+}
+
+fn resolve_deadline(e: &Env, schedule: &MarketSchedule) -> u64 {
+    schedule
+        .resolve_deadline
+        .unwrap_or(schedule.resolve_after + get_resolution_window(e))
+}
+
+fn validate_early_bonus(early_bonus: i128) {
+    if !(0..=MAX_EARLY_BONUS).contains(&early_bonus) {
+        panic!("early bonus must be between 0 and 10000 (0-100%)");
+    }
+}
+
+/// Weight of a stake placed at `placed_at`. The bonus decays linearly from
+/// `early_bonus` at `created_at` to nothing at `close_time`.
+fn weighted_stake(market: &Market, amount: i128, placed_at: u64) -> i128 {
+    let remaining = (market.close_time - placed_at) as i128;
+    let duration = (market.close_time - market.created_at) as i128;
+    let multiplier = 10000 + (market.early_bonus * remaining) / duration;
+    (amount * multiplier) / 10000
+}
+
+/// Gross share of the pot owed to a winning stake, split by weighted stake.
+/// If nobody took the other side the stake is simply returned.
+fn pot_share(market: &Market, winning_side: PredictionSide, stake: i128, weighted: i128) -> i128 {
+    let total_pot = market.total_yes + market.total_no;
+    match winning_side {
+        PredictionSide::Yes => {
+            if market.total_no == 0 {
+                stake
+            } else {
+                (weighted * total_pot) / market.weighted_yes
+            }
+        }
+        PredictionSide::No => {
+            if market.total_yes == 0 {
+                stake
+            } else {
+                (weighted * total_pot) / market.weighted_no
+            }
+        }
+    }
+}
+
+fn winning_pool(market: &Market, side: PredictionSide) -> i128 {
+    match side {
+        PredictionSide::Yes => market.total_yes,
+        PredictionSide::No => market.total_no,
+    }
+}
+
+fn get_winning_side(e: &Env, market_id: u64) -> Option<PredictionSide> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::WinningSide(market_id))
+}
+
+fn set_winning_side(e: &Env, market_id: u64, side: PredictionSide) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::WinningSide(market_id), &side);
+}
+
+/// Add a stake to a user's prediction and the market totals. The caller is
+/// responsible for moving the tokens and saving the market.
+fn record_prediction(
+    e: &Env,
+    market: &mut Market,
+    market_id: u64,
+    user: &Address,
+    side: PredictionSide,
+    amount: i128,
+    placed_at: u64,
+) {
+    let weighted = weighted_stake(market, amount, placed_at);
+
+    // Update user's prediction
+    let existing_prediction = get_user_prediction(e, user, market_id);
+
+    let new_prediction = if let Some(mut pred) = existing_prediction {
+        // If changing sides, move the previous stake over to the new side.
+        // It is re-weighted as if placed now, so a late switch can't keep
+        // the bonus earned on the other side.
+        if pred.side != side {
+            match pred.side {
+                PredictionSide::Yes => {
+                    market.total_yes -= pred.amount;
+                    market.weighted_yes -= pred.weighted;
+                }
+                PredictionSide::No => {
+                    market.total_no -= pred.amount;
+                    market.weighted_no -= pred.weighted;
+                }
+            }
+            pred.weighted = weighted_stake(market, pred.amount, placed_at);
+            match side {
+                PredictionSide::Yes => {
+                    market.total_yes += pred.amount;
+                    market.weighted_yes += pred.weighted;
+                }
+                PredictionSide::No => {
+                    market.total_no += pred.amount;
+                    market.weighted_no += pred.weighted;
+                }
+            }
+        }
+
+        Prediction {
+            side,
+            amount: amount + pred.amount,
+            weighted: weighted + pred.weighted,
+        }
+    } else {
+        // New prediction
+        Prediction {
+            side,
+            amount,
+            weighted,
+        }
+    };
+
+    set_user_prediction(e, user, market_id, &new_prediction);
+
+    set_user_volume(e, user, get_user_volume(e, user) + amount);
+
+    // Update market totals
+    match side {
+        PredictionSide::Yes => {
+            market.total_yes += amount;
+            market.weighted_yes += weighted;
+        }
+        PredictionSide::No => {
+            market.total_no += amount;
+            market.weighted_no += weighted;
+        }
+    }
+}
+
+/// Gross pot share of a winning stake and the platform fee charged on it,
+/// on the fee basis fixed when the market was created. Claims and the quote
+/// views all go through here so their numbers agree.
+fn winning_payout(
+    market: &Market,
+    winning_side: PredictionSide,
+    stake: i128,
+    weighted: i128,
+    fee_rate: i128,
+) -> (i128, i128) {
+    let user_pot_share = pot_share(market, winning_side, stake, weighted);
+    let fee_base = match market.fee_basis {
+        FeeBasis::Gross => user_pot_share,
+        FeeBasis::Profit => (user_pot_share - stake).max(0),
+    };
+    (user_pot_share, (fee_base * fee_rate) / 10000)
+}
+
+/// Payout after the base platform fee of a new stake on `side`, if the
+/// market resolved that way right after it was placed.
+fn quote_new_stake(market: &Market, side: PredictionSide, amount: i128, placed_at: u64) -> i128 {
+    let mut market = market.clone();
+    let weighted = weighted_stake(&market, amount, placed_at);
+    match side {
+        PredictionSide::Yes => {
+            market.total_yes += amount;
+            market.weighted_yes += weighted;
+        }
+        PredictionSide::No => {
+            market.total_no += amount;
+            market.weighted_no += weighted;
+        }
+    }
+
+    let (pot_share, platform_fee) =
+        winning_payout(&market, side, amount, weighted, market.fee_rate);
+
+    pot_share - platform_fee
+}
+
+/// Work out what a user's claim on a market would pay, without changing
+/// any state.
+fn quote_claim(e: &Env, user: &Address, market_id: u64) -> Result<ClaimQuote, ClaimStatus> {
+    let pred = match get_user_prediction(e, user, market_id) {
+        Some(pred) => pred,
+        None => return Err(ClaimStatus::NoPrediction),
+    };
+
+    let market = get_market(e, market_id);
+
+    // Cancelled markets refund the full stake without fees
+    if is_cancelled(e, &market) {
+        return Ok(ClaimQuote {
+            market,
+            stake: pred.amount,
+            pot_share: pred.amount,
+            platform_fee: 0,
+            status: ClaimStatus::Refunded,
+        });
+    }
+
+    if !market.resolved {
+        return Err(ClaimStatus::NotResolved);
+    }
+
+    if is_contested(e, market_id) {
+        return Err(ClaimStatus::Contested);
+    }
+
+    let winning_side = get_winning_side(e, market_id).unwrap();
+
+    // Check if user predicted correctly
+    if pred.side != winning_side {
+        return Err(ClaimStatus::NotWinner);
+    }
+
+    if claim_deadline_passed(e, &get_settlement(e, market_id)) {
+        return Err(ClaimStatus::ClaimWindowClosed);
+    }
+
+    // Calculate winnings based on market odds, with the platform fee fixed
+    // at creation discounted by the user's fee tier
+    let (pot_share, platform_fee) = winning_payout(
+        &market,
+        winning_side,
+        pred.amount,
+        pred.weighted,
+        tiered_fee_rate(e, user, market.fee_rate),
+    );
+
+    Ok(ClaimQuote {
+        market,
+        stake: pred.amount,
+        pot_share,
+        platform_fee,
+        status: ClaimStatus::Paid,
+    })
+}
+
+/// Settle a user's claim on a market without moving any tokens.
+/// Returns the token and net amount owed to the user.
+fn settle_claim(e: &Env, user: &Address, market_id: u64) -> Result<Payout, ClaimStatus> {
+    let quote = quote_claim(e, user, market_id)?;
+    let market = quote.market;
+    let mut settlement = get_settlement(e, market_id);
+
+    if quote.status == ClaimStatus::Refunded {
+        settlement.distributed += quote.stake;
+        set_settlement(e, market_id, &settlement);
+
+        // A refunded stake no longer counts towards the volume tiers
+        set_user_volume(e, user, get_user_volume(e, user) - quote.stake);
+
+        e.storage()
+            .persistent()
+            .remove(&DataKey::UserPredictions(user.clone(), market_id));
+        clear_referral(e, &market, market_id, user, true);
+
+        return Ok(Payout {
+            token: market.token,
+            amount: quote.stake,
+            status: ClaimStatus::Refunded,
+        });
+    }
+
+    let platform_fee = quote.platform_fee;
+    let winnings = quote.pot_share - platform_fee;
+
+    // Liquidity providers of the market earn a cut of the fee
+    let lp_fee = if market.lp_yes + market.lp_no > 0 {
+        (platform_fee * get_lp_fee_share(e)) / 10000
+    } else {
+        0
+    };
+
+    // The user's referrer earns a cut of what is left
+    let referral_fee = match get_referrer(e, user, market_id) {
+        Some(referrer) => {
+            let referral_fee = ((platform_fee - lp_fee) * get_referral_share(e)) / 10000;
+            let mut stats = get_referrer_stats(e, &referrer, &market.token);
+            stats.earned += referral_fee;
+            set_referrer_stats(e, &referrer, &market.token, &stats);
+            referral_fee
+        }
+        None => 0,
+    };
+
+    // Part of the platform's own cut backs the insurance fund
+    let insurance_fee =
+        ((platform_fee - lp_fee - referral_fee) * get_insurance_share(e)) / 10000;
+    if insurance_fee > 0 {
+        let fund = get_insurance_fund(e, &market.token) + insurance_fee;
+        set_insurance_fund(e, &market.token, fund);
+    }
+
+    // Track what has been paid out so the rounding remainder can be swept later
+    settlement.claimed_stake += quote.stake;
+    settlement.distributed += quote.pot_share;
+    settlement.lp_fees += lp_fee;
+    set_settlement(e, market_id, &settlement);
+    add_collected_fees(
+        e,
+        &market.token,
+        platform_fee - lp_fee - referral_fee - insurance_fee,
+    );
+
+    // Clear user prediction
+    e.storage()
+        .persistent()
+        .remove(&DataKey::UserPredictions(user.clone(), market_id));
+    clear_referral(e, &market, market_id, user, false);
+
+    Ok(Payout {
+        token: market.token,
+        amount: winnings,
+        status: ClaimStatus::Paid,
+    })
+}
+
+/// Resolve a market once the caller has been authorized.
+fn resolve(
+    e: &Env,
+    market_id: u64,
+    winning_side: PredictionSide,
+    resolver: &Address,
+    evidence: &ResolutionEvidence,
+) {
+    let mut market = get_market(e, market_id);
+
+    // A contested resolution is replaced, or upheld, by the resolver's vote
+    let contested = is_contested(e, market_id);
+    if contested {
+        if Some(resolver.clone()) != get_resolver(e) {
+            panic!("resolution contested");
+        }
+    } else if market.resolved {
+        panic!("market already resolved");
+    }
+
+    if evidence.reference.len() > MAX_URI_LEN {
+        panic!("evidence reference too long");
+    }
+
+    if evidence.justification.len() > MAX_REASON_LEN {
+        panic!("justification too long");
+    }
+
+    let current_time = e.ledger().timestamp();
+    if !contested {
+        if current_time < market.resolve_after {
+            panic!("too early to resolve");
+        }
+
+        if current_time > market.resolve_deadline {
+            panic!("resolution deadline passed");
+        }
+
+        // A conditional market can't resolve before its parent
+        if let MarketCondition::Parent(parent_id, _) = market.condition {
+            let parent = get_market(e, parent_id);
+            if !parent.resolved && !is_cancelled(e, &parent) {
+                panic!("parent market not resolved");
+            }
+        }
+
+        if is_cancelled(e, &market) {
+            panic!("market cancelled");
+        }
+    }
+
+    market.resolved = true;
+    set_winning_side(e, market_id, winning_side);
+
+    if contested {
+        // Settled for good, a resolution can only be contested once
+        e.storage()
+            .persistent()
+            .set(&DataKey::Contested(market_id), &false);
+    } else {
+        // Creators build reputation with markets that see a resolution
+        let reputation = get_creator_reputation(e, &market.creator) + 1;
+        e.storage()
+            .persistent()
+            .set(&DataKey::CreatorReputation(market.creator.clone()), &reputation);
+
+        // The parent's outcome is now relied upon by this market's payouts
+        if let MarketCondition::Parent(parent_id, _) = market.condition {
+            let resolved_children = get_resolved_children(e, parent_id) + 1;
+            e.storage()
+                .persistent()
+                .set(&DataKeyExt::ResolvedChildren(parent_id), &resolved_children);
+        }
+    }
+
+    // The claim window is fixed at resolution time
+    let claim_window = get_claim_window(e);
+    let mut settlement = get_settlement(e, market_id);
+    settlement.resolved_at = current_time;
+    settlement.claim_deadline = if claim_window > 0 {
+        Some(current_time + claim_window)
+    } else {
+        None
+    };
+    set_settlement(e, market_id, &settlement);
+
+    set_market(e, market_id, &market);
+
+    let resolution = Resolution {
+        winning_side,
+        resolver: resolver.clone(),
+        evidence: evidence.clone(),
+        resolved_at: current_time,
+    };
+    e.storage()
+        .persistent()
+        .set(&DataKey::Resolutions(market_id), &resolution);
+
+    MarketResolved {
+        market_id,
+        winning_side,
+        resolver: resolver.clone(),
+        evidence: evidence.clone(),
+    }
+    .publish(e);
+}
+
+// Contract metadata
+contractmeta!(
+    key = "Description",
+    val = "Decentralized Prediction Market - Bet on future events with decentralized resolution"
+);
+
+#[contract]
+pub struct PredictionMarket;
+
+#[contractimpl]
+impl PredictionMarket {
+    /// Initialize the contract with an admin address
+    pub fn initialize(e: Env, admin: Address) {
+        if has_admin(&e) {
+            panic!("already initialized");
+        }
+        set_admin(&e, &admin);
+        set_market_count(&e, 0);
+        set_platform_fee_rate(&e, 200); // 2% default fee
+    }
+
+    /// Create a new prediction market
+    pub fn create_market(e: Env, creator: Address, params: MarketParams) -> u64 {
+        creator.require_auth();
+        check_creation_policy(&e, &creator);
+
+        new_market(&e, &creator, params, MarketCondition::None)
+    }
+
+    /// Create a market that only settles if a parent market resolves to the
+    /// required outcome, and is cancelled with full refunds otherwise
+    pub fn create_conditional_market(
+        e: Env,
+        creator: Address,
+        params: MarketParams,
+        parent_market_id: u64,
+        parent_outcome: PredictionSide,
+    ) -> u64 {
+        creator.require_auth();
+        check_creation_policy(&e, &creator);
+
+        let parent = get_market(&e, parent_market_id);
+        if parent.resolved || is_cancelled(&e, &parent) {
+            panic!("parent market already settled");
+        }
+
+        new_market(
+            &e,
+            &creator,
+            params,
+            MarketCondition::Parent(parent_market_id, parent_outcome),
+        )
+    }
+
+    /// Register a recurring market series. Rounds are opened with `roll_series`.
+    pub fn create_series(e: Env, creator: Address, params: SeriesParams) -> u64 {
+        creator.require_auth();
+        check_creation_policy(&e, &creator);
+
+        if params.duration == 0 {
+            panic!("duration must be positive");
+        }
+
+        if params.cadence < params.duration {
+            panic!("cadence must not be shorter than duration");
+        }
+
+        validate_metadata(&params.metadata);
+
+        let series = MarketSeries {
+            creator,
+            question: params.question,
+            description: params.description,
+            token: params.token,
+            duration: params.duration,
+            cadence: params.cadence,
+            metadata: params.metadata,
+            rounds: 0,
+            current_market_id: None,
+            last_rolled_at: 0,
+        };
+
+        let series_id = get_series_count(&e) + 1;
+        set_series(&e, series_id, &series);
+        set_series_count(&e, series_id);
+
+        series_id
+    }
+
+    /// Open the next market of a series. Callable by anyone once the previous
+    /// round has ended and a full cadence has elapsed since it was opened.
+    pub fn roll_series(e: Env, series_id: u64) -> u64 {
+        let mut series = get_series(&e, series_id);
+        let current_time = e.ledger().timestamp();
+
+        // The creator must still be allowed to create markets
+        check_creation_policy(&e, &series.creator);
+
+        if let Some(previous_id) = series.current_market_id {
+            let previous = get_market(&e, previous_id);
+            if current_time < previous.close_time {
+                panic!("previous round not ended");
+            }
+            if current_time < series.last_rolled_at + series.cadence {
+                panic!("next round not due");
+            }
+        }
+
+        let params = MarketParams {
+            question: series.question.clone(),
+            description: series.description.clone(),
+            token: series.token.clone(),
+            schedule: MarketSchedule {
+                close_time: current_time + series.duration,
+                resolve_after: current_time + series.duration,
+                resolve_deadline: None,
+            },
+            metadata: series.metadata.clone(),
+            early_bonus: 0,
+            commit_reveal: false,
+        };
+        let market_id = new_market(&e, &series.creator, params, MarketCondition::None);
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::SeriesMarket(series_id, series.rounds), &market_id);
+
+        series.rounds += 1;
+        series.current_market_id = Some(market_id);
+        series.last_rolled_at = current_time;
+        set_series(&e, series_id, &series);
+
+        market_id
+    }
+
+    /// Update market metadata (creator only, before the first prediction)
+    pub fn update_market_metadata(
+        e: Env,
+        creator: Address,
+        market_id: u64,
+        metadata: MarketMetadata,
+    ) {
+        creator.require_auth();
+
+        let mut market = get_market(&e, market_id);
+
+        if creator != market.creator {
+            panic!("not authorized");
+        }
+
+        if market.total_yes + market.total_no > market.lp_yes + market.lp_no {
+            panic!("market already has predictions");
+        }
+
+        // Hidden stakes were committed against the current terms
+        if get_commitment_count(&e, market_id) > 0 {
+            panic!("market already has commitments");
+        }
+
+        validate_metadata(&metadata);
+
+        market.metadata = metadata;
+        set_market(&e, market_id, &market);
+    }
+
+    /// Place a prediction on a market. `limits` rejects the bet if
+    /// `quote_payout` for it has dropped below `min_payout`, or if the ledger
+    /// sequence is past `deadline`.
+    pub fn predict(
+        e: Env,
+        user: Address,
+        market_id: u64,
+        side: PredictionSide,
+        amount: i128,
+        referrer: Option<Address>,
+        limits: Option<PredictionLimits>,
+    ) {
+        user.require_auth();
+
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+
+        // Stale signed transactions can't execute
+        let limits = limits.unwrap_or(PredictionLimits {
+            min_payout: 0,
+            deadline: 0,
+        });
+        if limits.deadline > 0 && e.ledger().sequence() > limits.deadline {
+            panic!("deadline passed");
+        }
+
+        let mut market = get_market(&e, market_id);
+
+        if market.resolved {
+            panic!("market already resolved");
+        }
+
+        if is_cancelled(&e, &market) {
+            panic!("market cancelled");
+        }
+
+        if market.commit_reveal {
+            panic!("market requires commit-reveal");
+        }
+
+        let current_time = e.ledger().timestamp();
+        if current_time >= market.close_time {
+            panic!("betting closed");
+        }
+
+        // Reject the bet if the pool moved beyond the user's tolerance
+        if limits.min_payout > 0
+            && quote_new_stake(&market, side, amount, current_time) < limits.min_payout
+        {
+            panic!("payout below minimum");
+        }
+
+        // Transfer tokens from user to contract
+        transfer_tokens(&e, &market.token, &user, &e.current_contract_address(), amount);
+
+        record_prediction(&e, &mut market, market_id, &user, side, amount, current_time);
+        if let Some(referrer) = referrer {
+            record_referral(&e, &market, market_id, &user, &referrer, amount);
+        }
+
+        set_market(&e, market_id, &market);
+    }
+
+    /// Commit to a hidden prediction on a commit-reveal market, escrowing
+    /// `deposit`. The prediction only counts once revealed after close_time.
+    pub fn commit_prediction(
+        e: Env,
+        user: Address,
+        market_id: u64,
+        hash: BytesN<32>,
+        deposit: i128,
+    ) {
+        user.require_auth();
+
+        if deposit <= 0 {
+            panic!("deposit must be positive");
+        }
+
+        let market = get_market(&e, market_id);
+
+        if !market.commit_reveal {
+            panic!("market does not use commit-reveal");
+        }
+
+        if is_cancelled(&e, &market) {
+            panic!("market cancelled");
+        }
+
+        let current_time = e.ledger().timestamp();
+        if current_time >= market.close_time {
+            panic!("betting closed");
+        }
+
+        if get_commitment(&e, &user, market_id).is_some() {
+            panic!("already committed");
+        }
+
+        transfer_tokens(&e, &market.token, &user, &e.current_contract_address(), deposit);
+
+        let commitment = Commitment {
+            hash,
+            deposit,
+            committed_at: current_time,
+            penalty_rate: get_commit_penalty_rate(&e),
+        };
+        set_commitment(&e, &user, market_id, &commitment);
+
+        let count = get_commitment_count(&e, market_id);
+        e.storage()
+            .persistent()
+            .set(&DataKey::CommitmentCount(market_id), &(count + 1));
+    }
+
+    /// Reveal a committed prediction between close_time and resolve_after.
+    /// The stake joins the pool and the rest of the deposit is returned.
+    pub fn reveal_prediction(
+        e: Env,
+        user: Address,
+        market_id: u64,
+        side: PredictionSide,
+        amount: i128,
+        salt: BytesN<32>,
+    ) {
+        user.require_auth();
+
+        let commitment = match get_commitment(&e, &user, market_id) {
+            Some(commitment) => commitment,
+            None => panic!("no commitment found"),
+        };
+
+        let mut market = get_market(&e, market_id);
+
+        if is_cancelled(&e, &market) {
+            panic!("market cancelled");
+        }
+
+        let current_time = e.ledger().timestamp();
+        if current_time < market.close_time {
+            panic!("reveal window not open");
+        }
+        if market.resolved || current_time >= market.resolve_after {
+            panic!("reveal window closed");
+        }
+
+        if compute_commitment(&e, &user, market_id, side, amount, &salt) != commitment.hash {
+            panic!("commitment mismatch");
+        }
+
+        if amount <= 0 || amount > commitment.deposit {
+            panic!("amount must be positive and covered by the deposit");
+        }
+
+        remove_commitment(&e, &user, market_id);
+        record_prediction(
+            &e,
+            &mut market,
+            market_id,
+            &user,
+            side,
+            amount,
+            commitment.committed_at,
+        );
+        set_market(&e, market_id, &market);
+
+        let excess = commitment.deposit - amount;
+        if excess > 0 {
+            transfer_tokens(&e, &market.token, &e.current_contract_address(), &user, excess);
+        }
+    }
+
+    /// Return a commitment that was never revealed, once the reveal window
+    /// has closed. The penalty rate in force at commit time is kept as
+    /// platform fees unless the market was cancelled.
+    pub fn refund_commitment(e: Env, user: Address, market_id: u64) -> i128 {
+        user.require_auth();
+
+        let commitment = match get_commitment(&e, &user, market_id) {
+            Some(commitment) => commitment,
+            None => panic!("no commitment found"),
+        };
+
+        let market = get_market(&e, market_id);
+
+        let penalty = if is_cancelled(&e, &market) {
+            0
+        } else {
+            if !market.resolved && e.ledger().timestamp() < market.resolve_after {
+                panic!("reveal window still open");
+            }
+            (commitment.deposit * commitment.penalty_rate) / 10000
+        };
+
+        remove_commitment(&e, &user, market_id);
+        add_collected_fees(&e, &market.token, penalty);
+
+        let refund = commitment.deposit - penalty;
+        transfer_tokens(&e, &market.token, &e.current_contract_address(), &user, refund);
+
+        refund
+    }
+
+    /// Seed both sides of a market with balanced liquidity. The provider
+    /// receives one LP share per token deposited.
+    pub fn provide_liquidity(e: Env, provider: Address, market_id: u64, amount: i128) -> i128 {
+        provider.require_auth();
+
+        if amount < 2 {
+            panic!("amount must be at least 2");
+        }
+
+        let mut market = get_market(&e, market_id);
+
+        if market.resolved {
+            panic!("market already resolved");
+        }
+
+        if is_cancelled(&e, &market) {
+            panic!("market cancelled");
+        }
+
+        if e.ledger().timestamp() >= market.close_time {
+            panic!("betting closed");
+        }
+
+        transfer_tokens(&e, &market.token, &provider, &e.current_contract_address(), amount);
+
+        let yes = amount / 2;
+        let no = amount - yes;
+
+        let mut position = get_lp_position(&e, &provider, market_id).unwrap_or(LpPosition {
+            yes: 0,
+            no: 0,
+            principal_withdrawn: false,
+            fees_withdrawn: 0,
+        });
+        position.yes += yes;
+        position.no += no;
+        set_lp_position(&e, &provider, market_id, &position);
+
+        market.total_yes += yes;
+        market.total_no += no;
+        market.lp_yes += yes;
+        market.lp_no += no;
+        market.weighted_yes += yes;
+        market.weighted_no += no;
+        set_market(&e, market_id, &market);
+
+        amount
+    }
+
+    /// Withdraw a provider's pro-rata residual and fee cut after resolution,
+    /// or the full deposit if the market was cancelled. Can be called again
+    /// later to collect fees accrued since the last withdrawal. Past the claim
+    /// deadline only fees are paid, the residual is left to `sweep_unclaimed`.
+    pub fn withdraw_liquidity(e: Env, provider: Address, market_id: u64) -> i128 {
+        provider.require_auth();
+
+        let mut position = match get_lp_position(&e, &provider, market_id) {
+            Some(position) => position,
+            None => panic!("no liquidity position"),
+        };
+
+        let market = get_market(&e, market_id);
+        let mut settlement = get_settlement(&e, market_id);
+        let mut amount = 0;
+
+        if is_cancelled(&e, &market) {
+            if !position.principal_withdrawn {
+                amount = position.yes + position.no;
+                settlement.distributed += amount;
+                position.principal_withdrawn = true;
+            }
+        } else {
+            if !market.resolved {
+                panic!("market not resolved");
+            }
+
+            if is_contested(&e, market_id) {
+                panic!("resolution contested");
+            }
+
+            // Accrued fees are already counted as distributed, so they stay
+            // withdrawable after the claim deadline
+            if !position.principal_withdrawn && !claim_deadline_passed(&e, &settlement) {
+                let winning_side = get_winning_side(&e, market_id).unwrap();
+                let stake = match winning_side {
+                    PredictionSide::Yes => position.yes,
+                    PredictionSide::No => position.no,
+                };
+                // Seed liquidity carries no early-bettor bonus
+                let share = pot_share(&market, winning_side, stake, stake);
+
+                settlement.claimed_stake += stake;
+                settlement.distributed += share;
+                position.principal_withdrawn = true;
+                amount += share;
+            }
+
+            let fees_earned =
+                (settlement.lp_fees * (position.yes + position.no)) / (market.lp_yes + market.lp_no);
+            amount += fees_earned - position.fees_withdrawn;
+            position.fees_withdrawn = fees_earned;
+        }
+
+        set_settlement(&e, market_id, &settlement);
+        set_lp_position(&e, &provider, market_id, &position);
+
+        if amount > 0 {
+            transfer_tokens(&e, &market.token, &e.current_contract_address(), &provider, amount);
+        }
+
+        amount
+    }
+
+    /// Resolve a market (admin or resolver only) and distribute winnings.
+    /// The evidence is stored with the outcome, see `get_resolution`.
+    pub fn resolve_market(
+        e: Env,
+        admin: Address,
+        market_id: u64,
+        winning_side: PredictionSide,
+        evidence: ResolutionEvidence,
+    ) {
+        admin.require_auth();
+        
+        let market_admin = get_admin(&e);
+        if admin != market_admin && Some(admin.clone()) != get_resolver(&e) {
+            panic!("not authorized");
+        }
+
+        if admin == market_admin && multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        resolve(&e, market_id, winning_side, &admin, &evidence);
+    }
+
+    /// Put a resolved market's outcome to a new vote (resolver only). Payouts
+    /// are frozen until the resolver calls `resolve_market` again, which
+    /// replaces or upholds the outcome. Only possible once per market, before
+    /// anything has been paid out and before a conditional market depending
+    /// on the outcome has resolved.
+    pub fn contest_resolution(e: Env, resolver: Address, market_id: u64) {
+        resolver.require_auth();
+
+        if Some(resolver.clone()) != get_resolver(&e) {
+            panic!("not authorized");
+        }
+
+        let market = get_market(&e, market_id);
+        if !market.resolved {
+            panic!("market not resolved");
+        }
+
+        if e.storage().persistent().has(&DataKey::Contested(market_id)) {
+            panic!("resolution already contested");
+        }
+
+        if get_resolved_children(&e, market_id) > 0 {
+            panic!("dependent market already resolved");
+        }
+
+        let settlement = get_settlement(&e, market_id);
+        if settlement.distributed
+            + settlement.dust_swept
+            + settlement.unclaimed_swept
+            + settlement.insurance_paid
+            > 0
+        {
+            panic!("payouts already started");
+        }
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::Contested(market_id), &true);
+
+        ResolutionContested {
+            market_id,
+            resolver,
+            winning_side: get_winning_side(&e, market_id).unwrap(),
+        }
+        .publish(&e);
+    }
+
+    /// Invalidate a market nobody resolved before its deadline. Callable by
+    /// anyone; every participant can then reclaim their full stake.
+    pub fn expire_market(e: Env, market_id: u64) {
+        let mut market = get_market(&e, market_id);
+
+        if market.resolved {
+            panic!("market already resolved");
+        }
+
+        if market.cancelled {
+            panic!("market already cancelled");
+        }
+
+        if e.ledger().timestamp() <= market.resolve_deadline {
+            panic!("resolution deadline not passed");
+        }
+
+        market.cancelled = true;
+        set_market(&e, market_id, &market);
+
+        MarketExpired {
+            market_id,
+            resolve_deadline: market.resolve_deadline,
+        }
+        .publish(&e);
+    }
+
+    /// Flag a market for moderators to review. Each user can flag a market
+    /// once; returns the market's flag count.
+    pub fn flag_market(e: Env, user: Address, market_id: u64, reason: String) -> u32 {
+        user.require_auth();
+
+        get_market(&e, market_id);
+
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+
+        let key = DataKey::Flags(market_id, user.clone());
+        if e.storage().persistent().has(&key) {
+            panic!("already flagged");
+        }
+
+        let flag = MarketFlag {
+            reason: reason.clone(),
+            flagged_at: e.ledger().timestamp(),
+        };
+        e.storage().persistent().set(&key, &flag);
+
+        let flags = get_flag_count(&e, market_id) + 1;
+        e.storage()
+            .persistent()
+            .set(&DataKey::FlagCount(market_id), &flags);
+
+        MarketFlagged {
+            market_id,
+            user,
+            reason,
+            flags,
+        }
+        .publish(&e);
+
+        flags
+    }
+
+    /// Hide a market from list views (admin or moderator only). An
+    /// unresolved market is also cancelled, so every participant can reclaim
+    /// their full stake.
+    pub fn delist_market(e: Env, moderator: Address, market_id: u64, reason: String) {
+        moderator.require_auth();
+
+        if moderator != get_admin(&e) && !is_moderator(&e, &moderator) {
+            panic!("not authorized");
+        }
+
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+
+        let mut market = get_market(&e, market_id);
+        if market.delisted {
+            panic!("market already delisted");
+        }
+
+        let refunded = !market.resolved;
+        market.delisted = true;
+        if refunded {
+            market.cancelled = true;
+        }
+        set_market(&e, market_id, &market);
+
+        let delisting = Delisting {
+            moderator: moderator.clone(),
+            reason: reason.clone(),
+            delisted_at: e.ledger().timestamp(),
+        };
+        e.storage()
+            .persistent()
+            .set(&DataKey::Delistings(market_id), &delisting);
+
+        MarketDelisted {
+            market_id,
+            moderator,
+            reason,
+            refunded,
+        }
+        .publish(&e);
+    }
+
+    /// Claim winnings from a resolved market
+    pub fn claim_winnings(e: Env, user: Address, market_id: u64) -> i128 {
+        user.require_auth();
+
+        let payout = match settle_claim(&e, &user, market_id) {
+            Ok(payout) => payout,
+            Err(status) => panic!("{}", status.message()),
+        };
+
+        // Transfer winnings to user
+        transfer_tokens(&e, &payout.token, &e.current_contract_address(), &user, payout.amount);
+        forward_fees(&e, &payout.token);
+
+        payout.amount
+    }
+
+    /// Trigger a payout on behalf of a winner. Winnings always go to `user`,
+    /// `caller` only receives the keeper tip configured by the admin. Refunds
+    /// of cancelled markets are passed on in full, without a tip.
+    pub fn claim_for(e: Env, caller: Address, user: Address, market_id: u64) -> i128 {
+        caller.require_auth();
+
+        let payout = match settle_claim(&e, &user, market_id) {
+            Ok(payout) => payout,
+            Err(status) => panic!("{}", status.message()),
+        };
+
+        let tip = if payout.status == ClaimStatus::Paid {
+            (payout.amount * get_keeper_tip_rate(&e)) / 10000
+        } else {
+            0
+        };
+        let user_winnings = payout.amount - tip;
+
+        transfer_tokens(&e, &payout.token, &e.current_contract_address(), &user, user_winnings);
+        if tip > 0 {
+            transfer_tokens(&e, &payout.token, &e.current_contract_address(), &caller, tip);
+        }
+        forward_fees(&e, &payout.token);
+
+        user_winnings
+    }
+
+    /// Claim winnings from several resolved markets with a single auth.
+    /// Markets that can't be claimed are skipped and reported in the result,
+    /// payouts are aggregated into one transfer per token.
+    pub fn claim_many(e: Env, user: Address, market_ids: Vec<u64>) -> Vec<ClaimResult> {
+        user.require_auth();
+
+        if market_ids.len() > MAX_CLAIM_BATCH {
+            panic!("too many markets in batch");
+        }
+
+        let mut results = Vec::new(&e);
+        let mut payouts: Map<Address, i128> = Map::new(&e);
+
+        for market_id in market_ids.iter() {
+            let result = match settle_claim(&e, &user, market_id) {
+                Ok(payout) => {
+                    let total = payouts.get(payout.token.clone()).unwrap_or(0) + payout.amount;
+                    payouts.set(payout.token, total);
+                    ClaimResult {
+                        market_id,
+                        status: payout.status,
+                        amount: payout.amount,
+                    }
+                }
+                Err(status) => ClaimResult {
+                    market_id,
+                    status,
+                    amount: 0,
+                },
+            };
+            results.push_back(result);
+        }
+
+        for (token, amount) in payouts.iter() {
+            transfer_tokens(&e, &token, &e.current_contract_address(), &user, amount);
+            forward_fees(&e, &token);
+        }
+
+        results
+    }
+
+    /// Move the rounding remainder of a resolved market into platform fees.
+    /// Callable by anyone once every winning stake has been claimed. Winnings
+    /// left after the claim deadline go through `sweep_unclaimed` instead.
+    pub fn sweep_dust(e: Env, market_id: u64) -> i128 {
+        let market = get_market(&e, market_id);
+
+        if !market.resolved {
+            panic!("market not resolved");
+        }
+
+        if is_contested(&e, market_id) {
+            panic!("resolution contested");
+        }
+
+        let winning_side = get_winning_side(&e, market_id).unwrap();
+        let mut settlement = get_settlement(&e, market_id);
+
+        if settlement.claimed_stake < winning_pool(&market, winning_side) {
+            panic!("winnings not fully claimed");
+        }
+
+        let dust = remaining_collateral(&market, &settlement);
+
+        if dust > 0 {
+            settlement.dust_swept += dust;
+            set_settlement(&e, market_id, &settlement);
+            add_collected_fees(&e, &market.token, dust);
+        }
+
+        dust
+    }
+
+    /// Forward a token's collected fees (e.g. swept dust) to the treasury.
+    /// Callable by anyone, claims forward their fees automatically.
+    pub fn forward_fees(e: Env, token: Address) -> i128 {
+        if get_treasury(&e).is_none() {
+            panic!("treasury not set");
+        }
+
+        forward_fees(&e, &token)
+    }
+
+    /// Sweep collateral left unclaimed after the claim deadline (admin only,
+    /// through a proposal when multisig is enabled)
+    pub fn sweep_unclaimed(e: Env, admin: Address, market_id: u64, to: Address) -> i128 {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        sweep_unclaimed(&e, market_id, &to)
+    }
+
+    /// Compensate users of a market later found to be mis-resolved, out of
+    /// the insurance fund of the market's token (admin only, through a
+    /// proposal when multisig is enabled). Payouts on one market are capped
+    /// at the insurance cap share of its pool.
+    pub fn insurance_payout(
+        e: Env,
+        admin: Address,
+        market_id: u64,
+        recipients: Vec<Compensation>,
+    ) -> i128 {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        insurance_payout(&e, market_id, &recipients)
+    }
+
+    /// Get payout accounting for a market
+    pub fn get_market_settlement(e: Env, market_id: u64) -> MarketSettlement {
+        get_settlement(&e, market_id)
+    }
+
+    /// Get the implied probability of each side in basis points. An empty
+    /// pool is even.
+    pub fn get_odds(e: Env, market_id: u64) -> MarketOdds {
+        let market = get_market(&e, market_id);
+        let total_pot = market.total_yes + market.total_no;
+
+        let yes = if total_pot == 0 {
+            5000
+        } else {
+            (market.total_yes * 10000) / total_pot
+        };
+
+        MarketOdds {
+            yes,
+            no: 10000 - yes,
+        }
+    }
+
+    /// Quote the payout, after the platform fee, of a new `amount` stake on
+    /// `side` if the market resolved that way right after it was placed.
+    /// Fee tier discounts aren't applied.
+    pub fn quote_payout(e: Env, market_id: u64, side: PredictionSide, amount: i128) -> i128 {
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+
+        let market = get_market(&e, market_id);
+
+        if market.resolved {
+            panic!("market already resolved");
+        }
+
+        if is_cancelled(&e, &market) {
+            panic!("market cancelled");
+        }
+
+        let current_time = e.ledger().timestamp();
+        if current_time >= market.close_time {
+            panic!("betting closed");
+        }
+
+        quote_new_stake(&market, side, amount, current_time)
+    }
+
+    /// Get what `claim_winnings` would pay a user right now, 0 if nothing
+    pub fn get_claimable(e: Env, user: Address, market_id: u64) -> i128 {
+        match quote_claim(&e, &user, market_id) {
+            Ok(quote) => quote.pot_share - quote.platform_fee,
+            Err(_) => 0,
+        }
+    }
+
+    /// Check whether a market's resolution is under a dispute vote
+    pub fn is_resolution_contested(e: Env, market_id: u64) -> bool {
+        is_contested(&e, market_id)
+    }
+
+    /// Check whether a market has been cancelled (stakes are refundable)
+    pub fn is_market_cancelled(e: Env, market_id: u64) -> bool {
+        is_cancelled(&e, &get_market(&e, market_id))
+    }
+
+    /// Get market details
+    pub fn get_market(e: Env, market_id: u64) -> Market {
+        get_market(&e, market_id)
+    }
+
+    /// Get ids of markets in a category. Scans at most `limit` market ids
+    /// starting from `start`, so callers page through with increasing `start`.
+    pub fn get_markets_by_category(e: Env, category: String, start: u64, limit: u32) -> Vec<u64> {
+        if limit > MAX_PAGE_SIZE {
+            panic!("limit too large");
+        }
+
+        let mut market_ids = Vec::new(&e);
+        let end = get_market_count(&e).min(start.saturating_add(limit as u64).saturating_sub(1));
+
+        for market_id in start.max(1)..=end {
+            let market = get_market(&e, market_id);
+            if market.metadata.category == category && !market.delisted {
+                market_ids.push_back(market_id);
+            }
+        }
+
+        market_ids
+    }
+
+    /// Get a market series
+    pub fn get_series(e: Env, series_id: u64) -> MarketSeries {
+        get_series(&e, series_id)
+    }
+
+    /// Get the market opened by the latest roll of a series
+    pub fn get_series_current_market(e: Env, series_id: u64) -> Option<u64> {
+        get_series(&e, series_id).current_market_id
+    }
+
+    /// Get market ids of a series, oldest first, for rounds `start..start + limit`
+    pub fn get_series_markets(e: Env, series_id: u64, start: u32, limit: u32) -> Vec<u64> {
+        if limit > MAX_PAGE_SIZE {
+            panic!("limit too large");
+        }
+
+        let rounds = get_series(&e, series_id).rounds;
+        let mut market_ids = Vec::new(&e);
+
+        for round in start..rounds.min(start.saturating_add(limit)) {
+            let market_id: u64 = e
+                .storage()
+                .persistent()
+                .get(&DataKey::SeriesMarket(series_id, round))
+                .unwrap();
+            if !get_market(&e, market_id).delisted {
+                market_ids.push_back(market_id);
+            }
+        }
+
+        market_ids
+    }
+
+    /// Get total series count
+    pub fn get_series_count(e: Env) -> u64 {
+        get_series_count(&e)
+    }
+
+    /// Get a provider's liquidity position in a market
+    pub fn get_lp_position(e: Env, provider: Address, market_id: u64) -> Option<LpPosition> {
+        get_lp_position(&e, &provider, market_id)
+    }
+
+    /// Get a user's unrevealed commitment on a commit-reveal market
+    pub fn get_commitment(e: Env, user: Address, market_id: u64) -> Option<Commitment> {
+        get_commitment(&e, &user, market_id)
+    }
+
+    /// Compute the commitment hash for a user's hidden prediction on a market
+    pub fn compute_commitment(
+        e: Env,
+        user: Address,
+        market_id: u64,
+        side: PredictionSide,
+        amount: i128,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        compute_commitment(&e, &user, market_id, side, amount, &salt)
+    }
+
+    /// Stake governance tokens towards a fee discount tier
+    pub fn stake(e: Env, user: Address, amount: i128) {
+        user.require_auth();
+
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+
+        let token = match get_staking_token(&e) {
+            Some(token) => token,
+            None => panic!("staking not enabled"),
+        };
+
+        transfer_tokens(&e, &token, &user, &e.current_contract_address(), amount);
+        set_staked_balance(&e, &user, get_staked_balance(&e, &user) + amount);
+
+        let unlocks_at = e.ledger().timestamp() + STAKE_LOCKUP;
+        e.storage()
+            .persistent()
+            .set(&DataKey::StakeUnlocksAt(user.clone()), &unlocks_at);
+    }
+
+    /// Withdraw staked governance tokens once the lockup has passed
+    pub fn unstake(e: Env, user: Address, amount: i128) {
+        user.require_auth();
+
+        let staked = get_staked_balance(&e, &user);
+        if amount <= 0 || amount > staked {
+            panic!("invalid unstake amount");
+        }
+
+        let unlocks_at: u64 = e
+            .storage()
+            .persistent()
+            .get(&DataKey::StakeUnlocksAt(user.clone()))
+            .unwrap_or(0);
+        if e.ledger().timestamp() < unlocks_at {
+            panic!("stake still locked");
+        }
+
+        let token = get_staking_token(&e).unwrap();
+        set_staked_balance(&e, &user, staked - amount);
+        transfer_tokens(&e, &token, &e.current_contract_address(), &user, amount);
+    }
+
+    /// Withdraw a referrer's accrued rewards in one token
+    pub fn claim_referral_rewards(e: Env, referrer: Address, token: Address) -> i128 {
+        referrer.require_auth();
+
+        let mut stats = get_referrer_stats(&e, &referrer, &token);
+        let amount = stats.earned - stats.claimed;
+        if amount <= 0 {
+            panic!("no referral rewards");
+        }
+
+        stats.claimed = stats.earned;
+        set_referrer_stats(&e, &referrer, &token, &stats);
+
+        transfer_tokens(&e, &token, &e.current_contract_address(), &referrer, amount);
+
+        amount
+    }
+
+    /// Get a referrer's volume and rewards in one token
+    pub fn get_referrer_stats(e: Env, referrer: Address, token: Address) -> ReferrerStats {
+        get_referrer_stats(&e, &referrer, &token)
+    }
+
+    /// Get the referrer a user's prediction on a market is attributed to
+    pub fn get_referrer(e: Env, user: Address, market_id: u64) -> Option<Address> {
+        get_referrer(&e, &user, market_id)
+    }
+
+    /// Get user's prediction for a specific market
+    pub fn get_user_prediction(e: Env, user: Address, market_id: u64) -> Option<Prediction> {
+        get_user_prediction(&e, &user, market_id)
+    }
+
+    /// Get total market count
+    pub fn get_market_count(e: Env) -> u64 {
+        get_market_count(&e)
+    }
+
+    /// Get platform fees (including swept dust) held for a token
+    pub fn get_collected_fees(e: Env, token: Address) -> i128 {
+        get_collected_fees(&e, &token)
+    }
+
+    /// Get the insurance fund held for a token
+    pub fn get_insurance_fund(e: Env, token: Address) -> i128 {
+        get_insurance_fund(&e, &token)
+    }
+
+    /// Get the share of platform fees set aside for insurance (in basis points)
+    pub fn get_insurance_share(e: Env) -> i128 {
+        get_insurance_share(&e)
+    }
+
+    /// Set the share of platform fees set aside for insurance (admin only)
+    pub fn set_insurance_share(e: Env, admin: Address, share: i128) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_insurance_share(&e, share);
+    }
+
+    /// Get the per-incident insurance cap (in basis points of a market's pool)
+    pub fn get_insurance_cap(e: Env) -> i128 {
+        get_insurance_cap(&e)
+    }
+
+    /// Set the per-incident insurance cap (admin only)
+    pub fn set_insurance_cap(e: Env, admin: Address, cap: i128) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_insurance_cap(&e, cap);
+    }
+
+    /// Get who may create markets
+    pub fn get_creation_policy(e: Env) -> CreationPolicy {
+        get_creation_policy(&e)
+    }
+
+    /// Set who may create markets (admin only)
+    pub fn set_creation_policy(e: Env, admin: Address, policy: CreationPolicy) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_creation_policy(&e, policy);
+    }
+
+    /// Check whether a creator is on the allowlist
+    pub fn is_allowlisted(e: Env, creator: Address) -> bool {
+        is_allowlisted(&e, &creator)
+    }
+
+    /// Add a creator to or remove it from the allowlist (admin only)
+    pub fn set_allowlisted(e: Env, admin: Address, creator: Address, allowed: bool) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_allowlisted(&e, &creator, allowed);
+    }
+
+    /// Get the number of a creator's markets that were resolved
+    pub fn get_creator_reputation(e: Env, creator: Address) -> u32 {
+        get_creator_reputation(&e, &creator)
+    }
+
+    /// Get the reputation creators need under the open policy
+    pub fn get_min_creator_reputation(e: Env) -> u32 {
+        get_min_creator_reputation(&e)
+    }
+
+    /// Set the reputation creators need under the open policy (admin only).
+    /// 0 disables the threshold.
+    pub fn set_min_creator_reputation(e: Env, admin: Address, min_reputation: u32) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
 
-        // Transfer tokens from user to contract
-        transfer_tokens(&e, &market.token, &user, &e.current_contract_address(), amount);
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
 
-        // Update user's prediction
-        let existing_prediction = get_user_prediction(&e, &user, market_id);
-        
-        if let Some(pred) = existing_prediction {
-            // User already has a prediction, check if they're changing sides
-            let new_side = side.clone();
-            let new_amount = amount + pred.amount;
-            
-            // If changing sides, reverse the previous bet
-            match (&pred.side, &new_side) {
-                (PredictionSide::Yes, PredictionSide::No) | (PredictionSide::No, PredictionSide::Yes) => {
-                    // User is switching sides
-                    match pred.side {
-                        PredictionSide::Yes => market.total_yes -= pred.amount,
-                        PredictionSide::No => market.total_no -= pred.amount,
-                    }
-                }
-                _ => {
-                    // Same side, just adding more
-                }
-            }
-            
-            let new_prediction = Prediction {
-                side: new_side.clone(),
-                amount: new_amount,
-            };
-            
-            set_user_prediction(&e, &user, market_id, &new_prediction);
-        } else {
-            // New prediction
-            let new_prediction = Prediction {
-                side: side.clone(),
-                amount,
-            };
-            set_user_prediction(&e, &user, market_id, &new_prediction);
+        set_min_creator_reputation(&e, min_reputation);
+    }
+
+    /// Check whether an address can delist markets
+    pub fn is_moderator(e: Env, moderator: Address) -> bool {
+        is_moderator(&e, &moderator)
+    }
+
+    /// Grant or revoke the moderator role (admin only)
+    pub fn set_moderator(e: Env, admin: Address, moderator: Address, enabled: bool) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
         }
 
-        // Update market totals
-        match side {
-            PredictionSide::Yes => market.total_yes += amount,
-            PredictionSide::No => market.total_no += amount,
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
         }
 
-        set_market(&e, market_id, &market);
+        set_moderator(&e, &moderator, enabled);
+    }
+
+    /// Get a user's flag on a market
+    pub fn get_flag(e: Env, market_id: u64, user: Address) -> Option<MarketFlag> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Flags(market_id, user))
+    }
+
+    /// Get the number of users who flagged a market
+    pub fn get_flag_count(e: Env, market_id: u64) -> u32 {
+        get_flag_count(&e, market_id)
+    }
+
+    /// Get the moderation decision that delisted a market
+    pub fn get_delisting(e: Env, market_id: u64) -> Option<Delisting> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Delistings(market_id))
+    }
+
+    /// Get the contract allowed to resolve markets besides the admin
+    pub fn get_resolver(e: Env) -> Option<Address> {
+        get_resolver(&e)
+    }
+
+    /// Let a contract, e.g. the governance token's dispute vote, resolve
+    /// markets (admin only, through a proposal when multisig is enabled)
+    pub fn set_resolver(e: Env, admin: Address, resolver: Address) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_resolver(&e, &resolver);
+    }
+
+    /// Get the treasury platform fees are forwarded to
+    pub fn get_treasury(e: Env) -> Option<Address> {
+        get_treasury(&e)
+    }
+
+    /// Set the treasury platform fees are forwarded to (admin only, through
+    /// a proposal when multisig is enabled)
+    pub fn set_treasury(e: Env, admin: Address, treasury: Address) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_treasury(&e, &treasury);
+    }
+
+    /// Get platform fee rate
+    pub fn get_platform_fee_rate(e: Env) -> i128 {
+        get_platform_fee_rate(&e)
+    }
+
+    /// Get the fee rate charged to a user on winnings (in basis points)
+    pub fn get_effective_fee_rate(e: Env, user: Address) -> i128 {
+        get_effective_fee_rate(&e, &user)
+    }
+
+    /// Get a fee tier table
+    pub fn get_fee_tiers(e: Env, kind: FeeTierKind) -> Vec<FeeTier> {
+        get_fee_tiers(&e, kind)
+    }
+
+    /// Replace a fee tier table (admin only)
+    pub fn set_fee_tiers(e: Env, admin: Address, kind: FeeTierKind, tiers: Vec<FeeTier>) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_fee_tiers(&e, kind, &tiers);
+    }
+
+    /// Get a user's cumulative prediction volume
+    pub fn get_user_volume(e: Env, user: Address) -> i128 {
+        get_user_volume(&e, &user)
+    }
+
+    /// Get a user's staked governance token balance
+    pub fn get_staked_balance(e: Env, user: Address) -> i128 {
+        get_staked_balance(&e, &user)
+    }
+
+    /// Get the governance token accepted by `stake`
+    pub fn get_staking_token(e: Env) -> Option<Address> {
+        get_staking_token(&e)
+    }
+
+    /// Set the governance token accepted by `stake` (admin only, once)
+    pub fn set_staking_token(e: Env, admin: Address, token: Address) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if get_staking_token(&e).is_some() {
+            panic!("staking token already set");
+        }
+
+        e.storage().instance().set(&DataKey::StakingToken, &token);
+    }
+
+    /// Get a fee increase that hasn't applied yet
+    pub fn get_pending_fee_rate(e: Env) -> Option<PendingFeeRate> {
+        get_pending_fee_rate(&e).filter(|pending| e.ledger().timestamp() < pending.effective_at)
     }
 
-    /// Resolve a market (admin only) and distribute winnings
-    pub fn resolve_market(e: Env, admin: Address, market_id: u64, winning_side: PredictionSide) {
+    /// Set platform fee rate (admin only, through a proposal when multisig is enabled).
+    /// Increases are timelocked.
+    pub fn set_platform_fee_rate(e: Env, admin: Address, fee_rate: i128) {
         admin.require_auth();
         
         let market_admin = get_admin(&e);
@@ -263,120 +3487,285 @@ impl PredictionMarket {
             panic!("not authorized");
         }
 
-        let mut market = get_market(&e, market_id);
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
 
-        if market.resolved {
-            panic!("market already resolved");
+        change_platform_fee_rate(&e, fee_rate);
+    }
+
+    /// Get what the platform fee is charged on
+    pub fn get_fee_basis(e: Env) -> FeeBasis {
+        get_fee_basis(&e)
+    }
+
+    /// Get a switch to the gross fee basis that hasn't applied yet
+    pub fn get_pending_fee_basis(e: Env) -> Option<PendingFeeBasis> {
+        get_pending_fee_basis(&e).filter(|pending| e.ledger().timestamp() < pending.effective_at)
+    }
+
+    /// Set what the platform fee is charged on (admin only, through a
+    /// proposal when multisig is enabled). Switching to gross is timelocked.
+    pub fn set_fee_basis(e: Env, admin: Address, basis: FeeBasis) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
         }
 
-        let current_time = e.ledger().timestamp();
-        if current_time < market.end_time {
-            panic!("market not yet ended");
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
         }
 
-        market.resolved = true;
-        set_winning_side(&e, market_id, winning_side.clone());
+        change_fee_basis(&e, basis);
+    }
 
-        set_market(&e, market_id, &market);
+    /// Get the claim window (in seconds) applied to newly resolved markets
+    pub fn get_claim_window(e: Env) -> u64 {
+        get_claim_window(&e)
     }
 
-    /// Claim winnings from a resolved market
-    pub fn claim_winnings(e: Env, user: Address, market_id: u64) -> i128 {
-        user.require_auth();
+    /// Set the claim window (admin only), 0 disables the deadline
+    pub fn set_claim_window(e: Env, admin: Address, window: u64) {
+        admin.require_auth();
 
-        let prediction = get_user_prediction(&e, &user, market_id);
-        if prediction.is_none() {
-            panic!("no prediction found");
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
         }
 
-        let pred = prediction.unwrap();
-        let market = get_market(&e, market_id);
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
 
-        if !market.resolved {
-            panic!("market not resolved");
+        set_claim_window(&e, window);
+    }
+
+    /// Get keeper tip rate (in basis points of winnings)
+    pub fn get_keeper_tip_rate(e: Env) -> i128 {
+        get_keeper_tip_rate(&e)
+    }
+
+    /// Set keeper tip rate (admin only)
+    pub fn set_keeper_tip_rate(e: Env, admin: Address, tip_rate: i128) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
         }
 
-        let winning_side = get_winning_side(&e, market_id).unwrap();
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
 
-        // Check if user predicted correctly
-        let is_winner = match (&pred.side, &winning_side) {
-            (PredictionSide::Yes, PredictionSide::Yes) => true,
-            (PredictionSide::No, PredictionSide::No) => true,
-            _ => false,
-        };
+        set_keeper_tip_rate(&e, tip_rate);
+    }
+
+    /// Get the share of platform fees paid to liquidity providers (in basis points)
+    pub fn get_lp_fee_share(e: Env) -> i128 {
+        get_lp_fee_share(&e)
+    }
+
+    /// Set the share of platform fees paid to liquidity providers (admin only)
+    pub fn set_lp_fee_share(e: Env, admin: Address, share: i128) {
+        admin.require_auth();
 
-        if !is_winner {
-            panic!("not a winning prediction");
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
         }
 
-        // Calculate winnings based on market odds
-        let total_pot = market.total_yes + market.total_no;
-        let user_pot_share = match winning_side {
-            PredictionSide::Yes => {
-                if market.total_no == 0 {
-                    pred.amount
-                } else {
-                    (pred.amount * total_pot) / market.total_yes
-                }
-            }
-            PredictionSide::No => {
-                if market.total_yes == 0 {
-                    pred.amount
-                } else {
-                    (pred.amount * total_pot) / market.total_no
-                }
-            }
-        };
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
 
-        // Apply platform fee
-        let fee_rate = get_platform_fee_rate(&e);
-        let platform_fee = (user_pot_share * fee_rate) / 10000;
-        let winnings = user_pot_share - platform_fee;
+        set_lp_fee_share(&e, share);
+    }
 
-        // Transfer winnings to user
-        transfer_tokens(&e, &market.token, &e.current_contract_address(), &user, winnings);
+    /// Get the share of platform fees paid to referrers (in basis points)
+    pub fn get_referral_share(e: Env) -> i128 {
+        get_referral_share(&e)
+    }
 
-        // Clear user prediction
-        e.storage()
-            .persistent()
-            .remove(&DataKey::UserPredictions(user.clone(), market_id));
+    /// Set the share of platform fees paid to referrers (admin only)
+    pub fn set_referral_share(e: Env, admin: Address, share: i128) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
 
-        winnings
+        set_referral_share(&e, share);
     }
 
-    /// Get market details
-    pub fn get_market(e: Env, market_id: u64) -> Market {
-        get_market(&e, market_id)
+    /// Get the default resolution window (in seconds)
+    pub fn get_resolution_window(e: Env) -> u64 {
+        get_resolution_window(&e)
     }
 
-    /// Get user's prediction for a specific market
-    pub fn get_user_prediction(e: Env, user: Address, market_id: u64) -> Option<Prediction> {
-        get_user_prediction(&e, &user, market_id)
+    /// Set the default resolution window applied to markets created without
+    /// an explicit deadline (admin only)
+    pub fn set_resolution_window(e: Env, admin: Address, window: u64) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_resolution_window(&e, window);
     }
 
-    /// Get total market count
-    pub fn get_market_count(e: Env) -> u64 {
-        get_market_count(&e)
+    /// Get the penalty on unrevealed commitments (in basis points)
+    pub fn get_commit_penalty_rate(e: Env) -> i128 {
+        get_commit_penalty_rate(&e)
     }
 
-    /// Get platform fee rate
-    pub fn get_platform_fee_rate(e: Env) -> i128 {
-        get_platform_fee_rate(&e)
+    /// Set the penalty on unrevealed commitments (admin only)
+    pub fn set_commit_penalty_rate(e: Env, admin: Address, rate: i128) {
+        admin.require_auth();
+
+        let market_admin = get_admin(&e);
+        if admin != market_admin {
+            panic!("not authorized");
+        }
+
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_commit_penalty_rate(&e, rate);
     }
 
-    /// Set platform fee rate (admin only)
-    pub fn set_platform_fee_rate(e: Env, admin: Address, fee_rate: i128) {
+    /// Enable multisig approval for sensitive admin actions (admin only).
+    /// Once enabled, signers change through a `SetSigners` proposal.
+    pub fn set_signers(e: Env, admin: Address, signers: Vec<Address>, threshold: u32) {
         admin.require_auth();
-        
+
         let market_admin = get_admin(&e);
         if admin != market_admin {
             panic!("not authorized");
         }
 
-        set_platform_fee_rate(&e, fee_rate);
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        set_signers(&e, &signers, threshold);
+    }
+
+    /// Propose a sensitive admin action (signers only). The proposal counts
+    /// as the proposer's approval.
+    pub fn propose_action(e: Env, signer: Address, action: AdminAction) -> u64 {
+        require_signer(&e, &signer);
+
+        let proposal_id = get_proposal_count(&e) + 1;
+        e.storage()
+            .instance()
+            .set(&DataKey::ProposalCount, &proposal_id);
+
+        let expires_at = e.ledger().timestamp() + PROPOSAL_LIFETIME;
+        let mut proposal = Proposal {
+            id: proposal_id,
+            action: action.clone(),
+            proposer: signer.clone(),
+            approvals: Vec::new(&e),
+            signers_version: get_signers_version(&e),
+            expires_at,
+            executed: false,
+        };
+
+        ActionProposed {
+            proposal_id,
+            proposer: signer.clone(),
+            action,
+            expires_at,
+        }
+        .publish(&e);
+
+        approve(&e, &mut proposal, &signer);
+
+        proposal_id
+    }
+
+    /// Approve a pending proposal (signers only). The action executes as
+    /// soon as the threshold is met.
+    pub fn approve_action(e: Env, signer: Address, proposal_id: u64) {
+        require_signer(&e, &signer);
+
+        let mut proposal = get_proposal(&e, proposal_id);
+
+        if proposal.executed {
+            panic!("proposal already executed");
+        }
+
+        if e.ledger().timestamp() >= proposal.expires_at {
+            panic!("proposal expired");
+        }
+
+        if proposal.signers_version != get_signers_version(&e) {
+            panic!("signers changed since proposal");
+        }
+
+        if proposal.approvals.contains(&signer) {
+            panic!("already approved");
+        }
+
+        approve(&e, &mut proposal, &signer);
+    }
+
+    /// Get a proposal
+    pub fn get_proposal(e: Env, proposal_id: u64) -> Proposal {
+        get_proposal(&e, proposal_id)
+    }
+
+    /// Get proposals that can still be approved, among ids `start..start + limit`
+    pub fn get_pending_proposals(e: Env, start: u64, limit: u32) -> Vec<Proposal> {
+        if limit > MAX_PAGE_SIZE {
+            panic!("limit too large");
+        }
+
+        let mut proposals = Vec::new(&e);
+        let end = get_proposal_count(&e).min(start.saturating_add(limit as u64).saturating_sub(1));
+        let current_time = e.ledger().timestamp();
+        let signers_version = get_signers_version(&e);
+
+        for proposal_id in start.max(1)..=end {
+            let proposal = get_proposal(&e, proposal_id);
+            if !proposal.executed
+                && current_time < proposal.expires_at
+                && proposal.signers_version == signers_version
+            {
+                proposals.push_back(proposal);
+            }
+        }
+
+        proposals
+    }
+
+    /// Get the multisig signers, empty if multisig is disabled
+    pub fn get_signers(e: Env) -> Vec<Address> {
+        get_signers(&e)
+    }
+
+    /// Get the number of approvals a proposal needs
+    pub fn get_threshold(e: Env) -> u32 {
+        get_threshold(&e)
     }
 
-    /// Update admin (admin only)
+    /// Nominate a new admin, who takes over once they call `accept_admin`
+    /// (admin only, through a proposal when multisig is enabled)
     pub fn update_admin(e: Env, admin: Address, new_admin: Address) {
         admin.require_auth();
         
@@ -385,7 +3774,35 @@ impl PredictionMarket {
             panic!("not authorized");
         }
 
+        if multisig_enabled(&e) {
+            panic!("multisig approval required");
+        }
+
+        propose_admin(&e, &new_admin);
+    }
+
+    /// Accept a pending admin transfer (nominated admin only)
+    pub fn accept_admin(e: Env, new_admin: Address) {
+        new_admin.require_auth();
+
+        if get_pending_admin(&e) != Some(new_admin.clone()) {
+            panic!("not the pending admin");
+        }
+
+        let previous_admin = get_admin(&e);
         set_admin(&e, &new_admin);
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminTransferred {
+            previous_admin,
+            new_admin,
+        }
+        .publish(&e);
+    }
+
+    /// Get the nominated admin that hasn't accepted yet
+    pub fn get_pending_admin(e: Env) -> Option<Address> {
+        get_pending_admin(&e)
     }
 
     /// Get admin address
@@ -397,5 +3814,12 @@ impl PredictionMarket {
     pub fn get_winning_side(e: Env, market_id: u64) -> Option<PredictionSide> {
         get_winning_side(&e, market_id)
     }
+
+    /// Get the outcome of a market with its resolver, evidence and time
+    pub fn get_resolution(e: Env, market_id: u64) -> Option<Resolution> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Resolutions(market_id))
+    }
 }
 
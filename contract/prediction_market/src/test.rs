@@ -3,7 +3,8 @@ extern crate std;
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger}, token::StellarAssetClient, Address, Env, String,
+    testutils::{Address as _, Events, Ledger}, token::StellarAssetClient, vec, Address, Env,
+    Event, String,
 };
 
 fn create_token(e: &Env, admin: &Address) -> Address {
@@ -11,6 +12,30 @@ fn create_token(e: &Env, admin: &Address) -> Address {
     sac.address()
 }
 
+fn default_metadata(e: &Env) -> MarketMetadata {
+    MarketMetadata {
+        category: String::from_str(e, "general"),
+        tags: Vec::new(e),
+        resolution_source: String::from_str(e, ""),
+        image_uri: String::from_str(e, ""),
+    }
+}
+
+fn schedule(close_time: u64) -> MarketSchedule {
+    MarketSchedule {
+        close_time,
+        resolve_after: close_time,
+        resolve_deadline: None,
+    }
+}
+
+fn evidence(e: &Env) -> ResolutionEvidence {
+    ResolutionEvidence {
+        reference: String::from_str(e, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylq"),
+        justification: String::from_str(e, "Reported by the national weather service"),
+    }
+}
+
 #[test]
 fn test_initialize() {
     let e = Env::default();
@@ -46,7 +71,18 @@ fn test_create_market() {
     let description = String::from_str(&e, "Bitcoin price prediction");
     let end_time = 2000;
 
-    let market_id = client.create_market(&creator, &question, &description, &token, &end_time);
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(end_time),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
 
     assert_eq!(market_id, 1);
     assert_eq!(client.get_market_count(), 1);
@@ -55,8 +91,10 @@ fn test_create_market() {
     assert_eq!(market.creator, creator);
     assert_eq!(market.question, question);
     assert_eq!(market.token, token);
-    assert_eq!(market.end_time, end_time);
-    assert_eq!(market.resolved, false);
+    assert_eq!(market.close_time, end_time);
+    assert_eq!(market.resolve_after, end_time);
+    assert_eq!(market.resolve_deadline, end_time + 30 * 24 * 60 * 60);
+    assert!(!market.resolved);
     assert_eq!(market.total_yes, 0);
     assert_eq!(market.total_no, 0);
 }
@@ -84,10 +122,21 @@ fn test_predict_yes() {
     let description = String::from_str(&e, "Weather prediction");
     let end_time = 2000;
 
-    let market_id = client.create_market(&creator, &question, &description, &token, &end_time);
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(end_time),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
 
     let amount = 100_0000000;
-    client.predict(&creator, &market_id, &PredictionSide::Yes, &amount);
+    client.predict(&creator, &market_id, &PredictionSide::Yes, &amount, &None, &None);
 
     let market: Market = client.get_market(&market_id);
     assert_eq!(market.total_yes, amount);
@@ -122,10 +171,21 @@ fn test_predict_no() {
     let description = String::from_str(&e, "Weather prediction");
     let end_time = 2000;
 
-    let market_id = client.create_market(&creator, &question, &description, &token, &end_time);
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(end_time),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
 
     let amount = 100_0000000;
-    client.predict(&creator, &market_id, &PredictionSide::No, &amount);
+    client.predict(&creator, &market_id, &PredictionSide::No, &amount, &None, &None);
 
     let market: Market = client.get_market(&market_id);
     assert_eq!(market.total_yes, 0);
@@ -155,9 +215,20 @@ fn test_predict_zero_amount() {
     let description = String::from_str(&e, "Weather prediction");
     let end_time = 2000;
 
-    let market_id = client.create_market(&creator, &question, &description, &token, &end_time);
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(end_time),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
 
-    client.predict(&creator, &market_id, &PredictionSide::Yes, &0);
+    client.predict(&creator, &market_id, &PredictionSide::Yes, &0, &None, &None);
 }
 
 #[test]
@@ -187,24 +258,35 @@ fn test_resolve_and_claim_winnings() {
     let description = String::from_str(&e, "BTC prediction");
     let end_time = 2000;
 
-    let market_id = client.create_market(&creator, &question, &description, &token, &end_time);
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(end_time),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
 
     // User1 predicts Yes with 100 tokens
-    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000);
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
     
     // User2 predicts No with 200 tokens
-    client.predict(&user2, &market_id, &PredictionSide::No, &200_0000000);
+    client.predict(&user2, &market_id, &PredictionSide::No, &200_0000000, &None, &None);
 
     // Resolve market with Yes winning
     e.ledger().set_timestamp(2000);
-    client.resolve_market(&admin, &market_id, &PredictionSide::Yes);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
 
     // User1 claims winnings
     let winnings = client.claim_winnings(&user1, &market_id);
     
-    // User1 should receive approximately 300 tokens (less platform fee)
-    // Winnings = (100 * 300) / 100 - 2% = 300 * 0.98 = 294
-    assert!(winnings > 200_0000000 && winnings < 300_0000000);
+    // User1 receives the 300 pool less the platform fee on the 200 profit
+    // Winnings = (100 * 300) / 100 - 2% * 200 = 300 - 4 = 296
+    assert_eq!(winnings, 296_0000000);
 
     // User2 should not be able to claim
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -236,18 +318,28 @@ fn test_multiple_markets() {
     // Create multiple markets
     let market1_id = client.create_market(
         &creator,
-        &String::from_str(&e, "Market 1"),
-        &String::from_str(&e, "Description 1"),
-        &token,
-        &end_time,
+        &MarketParams {
+            question: String::from_str(&e, "Market 1"),
+            description: String::from_str(&e, "Description 1"),
+            token: token.clone(),
+            schedule: schedule(end_time),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
     );
 
     let market2_id = client.create_market(
         &creator,
-        &String::from_str(&e, "Market 2"),
-        &String::from_str(&e, "Description 2"),
-        &token,
-        &end_time,
+        &MarketParams {
+            question: String::from_str(&e, "Market 2"),
+            description: String::from_str(&e, "Description 2"),
+            token: token.clone(),
+            schedule: schedule(end_time),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
     );
 
     assert_eq!(market1_id, 1);
@@ -266,9 +358,21 @@ fn test_set_platform_fee_rate() {
 
     client.initialize(&admin);
 
-    // Set fee rate to 5% (500 basis points)
+    // Set fee rate to 5% (500 basis points), increases are timelocked
     client.set_platform_fee_rate(&admin, &500);
+    assert_eq!(client.get_platform_fee_rate(), 200);
+    assert_eq!(
+        client.get_pending_fee_rate(),
+        Some(PendingFeeRate { rate: 500, effective_at: 2 * 24 * 60 * 60 })
+    );
+
+    e.ledger().set_timestamp(2 * 24 * 60 * 60);
     assert_eq!(client.get_platform_fee_rate(), 500);
+    assert_eq!(client.get_pending_fee_rate(), None);
+
+    // Decreases apply immediately
+    client.set_platform_fee_rate(&admin, &100);
+    assert_eq!(client.get_platform_fee_rate(), 100);
 }
 
 #[test]
@@ -287,3 +391,3692 @@ fn test_non_admin_set_fee() {
     client.set_platform_fee_rate(&non_admin, &500);
 }
 
+
+#[test]
+fn test_switch_sides_moves_existing_stake() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user, &1000_0000000);
+
+    let market_id = client.create_market(
+        &user,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user, &market_id, &PredictionSide::No, &50_0000000, &None, &None);
+
+    // The whole stake follows the user to the new side
+    let market: Market = client.get_market(&market_id);
+    assert_eq!(market.total_yes, 0);
+    assert_eq!(market.total_no, 150_0000000);
+
+    let pred = client.get_user_prediction(&user, &market_id).unwrap();
+    assert_eq!(pred.side, PredictionSide::No);
+    assert_eq!(pred.amount, 150_0000000);
+}
+
+#[test]
+fn test_sweep_dust_after_all_claims() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_fee_basis(&admin, &FeeBasis::Gross);
+
+    // The gross basis charges more, so it applies after the fee timelock
+    let start = 1000 + 2 * 24 * 60 * 60;
+    e.ledger().set_timestamp(start);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let loser = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    for user in [&user1, &user2, &user3, &loser] {
+        token_client.mint(user, &1000);
+    }
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will BTC hit $100k?"),
+            description: String::from_str(&e, "BTC prediction"),
+            token: token.clone(),
+            schedule: schedule(start + 1000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    // Pot of 400 split three ways leaves a remainder of 1
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::Yes, &100, &None, &None);
+    client.predict(&user3, &market_id, &PredictionSide::Yes, &100, &None, &None);
+    client.predict(&loser, &market_id, &PredictionSide::No, &100, &None, &None);
+
+    e.ledger().set_timestamp(start + 1000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    client.claim_winnings(&user1, &market_id);
+    client.claim_winnings(&user2, &market_id);
+
+    // Not every winner has claimed yet
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.sweep_dust(&market_id);
+    }));
+    assert!(result.is_err());
+
+    client.claim_winnings(&user3, &market_id);
+
+    // Each winner paid 133 gross, of which 2 went to fees
+    assert_eq!(client.get_collected_fees(&token), 6);
+    assert_eq!(client.sweep_dust(&market_id), 1);
+    assert_eq!(client.get_collected_fees(&token), 7);
+
+    // Everything left in the contract is accounted for as fees
+    let balance = soroban_sdk::token::Client::new(&e, &token).balance(&contract_id);
+    assert_eq!(balance, 7);
+
+    // Sweeping again is a no-op
+    assert_eq!(client.sweep_dust(&market_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "market not resolved")]
+fn test_sweep_dust_unresolved_market() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.sweep_dust(&market_id);
+}
+
+#[test]
+fn test_sweep_unclaimed_after_claim_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_claim_window(&admin, &500);
+    assert_eq!(client.get_claim_window(), 500);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+    token_client.mint(&user3, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will BTC hit $100k?"),
+            description: String::from_str(&e, "BTC prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user3, &market_id, &PredictionSide::No, &200_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    let settlement = client.get_market_settlement(&market_id);
+    assert_eq!(settlement.resolved_at, 2000);
+    assert_eq!(settlement.claim_deadline, Some(2500));
+
+    // Too early to sweep
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.sweep_unclaimed(&admin, &market_id, &treasury);
+    }));
+    assert!(result.is_err());
+
+    client.claim_winnings(&user1, &market_id);
+
+    // User2 never claims within the window
+    e.ledger().set_timestamp(2501);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.claim_winnings(&user2, &market_id);
+    }));
+    assert!(result.is_err());
+
+    // Unclaimed winnings aren't dust, so nobody can front-run the admin sweep
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.sweep_dust(&market_id);
+    }));
+    assert!(result.is_err());
+
+    let swept = client.sweep_unclaimed(&admin, &market_id, &treasury);
+    assert_eq!(swept, 200_0000000);
+
+    let event = UnclaimedSwept {
+        market_id,
+        to: treasury.clone(),
+        amount: swept,
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&treasury), 200_0000000);
+
+    let settlement = client.get_market_settlement(&market_id);
+    assert_eq!(settlement.unclaimed_swept, 200_0000000);
+
+    // Only user1's fee is left behind
+    assert_eq!(token_view.balance(&contract_id), client.get_collected_fees(&token));
+    assert_eq!(client.sweep_unclaimed(&admin, &market_id, &treasury), 0);
+}
+
+#[test]
+#[should_panic(expected = "claim window still open")]
+fn test_sweep_unclaimed_without_claim_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // Without a configured window claims never expire
+    e.ledger().set_timestamp(1_000_000);
+    client.sweep_unclaimed(&admin, &market_id, &treasury);
+}
+
+#[test]
+fn test_claim_many_aggregates_and_skips() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_platform_fee_rate(&admin, &0);
+
+    let creator = Address::generate(&e);
+    let user = Address::generate(&e);
+    let other = Address::generate(&e);
+    let token_a = create_token(&e, &admin);
+    let token_b = create_token(&e, &admin);
+
+    for token in [&token_a, &token_b] {
+        let token_client = StellarAssetClient::new(&e, token);
+        token_client.mint(&user, &1000);
+        token_client.mint(&other, &1000);
+    }
+
+    let question = String::from_str(&e, "Will it rain tomorrow?");
+    let description = String::from_str(&e, "Weather prediction");
+
+    let won_a1 = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token_a.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let won_a2 = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token_a.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let won_b = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token_b.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let lost = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token_a.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let open = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token_a.clone(),
+            schedule: schedule(3000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    for market_id in [won_a1, won_a2, won_b, lost, open] {
+        client.predict(&user, &market_id, &PredictionSide::Yes, &100, &None, &None);
+        client.predict(&other, &market_id, &PredictionSide::No, &100, &None, &None);
+    }
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &won_a1, &PredictionSide::Yes, &evidence(&e));
+    client.resolve_market(&admin, &won_a2, &PredictionSide::Yes, &evidence(&e));
+    client.resolve_market(&admin, &won_b, &PredictionSide::Yes, &evidence(&e));
+    client.resolve_market(&admin, &lost, &PredictionSide::No, &evidence(&e));
+
+    // Already claimed markets are skipped
+    client.claim_winnings(&user, &won_a2);
+
+    let results = client.claim_many(&user, &vec![&e, won_a1, won_a2, won_b, lost, open]);
+    assert_eq!(results.len(), 5);
+
+    let expected = [
+        (won_a1, ClaimStatus::Paid, 200),
+        (won_a2, ClaimStatus::NoPrediction, 0),
+        (won_b, ClaimStatus::Paid, 200),
+        (lost, ClaimStatus::NotWinner, 0),
+        (open, ClaimStatus::NotResolved, 0),
+    ];
+    for (i, (market_id, status, amount)) in expected.iter().enumerate() {
+        let result = results.get(i as u32).unwrap();
+        assert_eq!(result.market_id, *market_id);
+        assert_eq!(result.status, *status);
+        assert_eq!(result.amount, *amount);
+    }
+
+    // Token A: four markets staked, one single claim and one batched payout
+    let token_a_client = soroban_sdk::token::Client::new(&e, &token_a);
+    let token_b_client = soroban_sdk::token::Client::new(&e, &token_b);
+    assert_eq!(token_a_client.balance(&user), 1000 - 4 * 100 + 200 + 200);
+    assert_eq!(token_b_client.balance(&user), 1000 - 100 + 200);
+
+    // Unresolved market can still be claimed later
+    assert!(client.get_user_prediction(&user, &open).is_some());
+}
+
+#[test]
+#[should_panic(expected = "too many markets in batch")]
+fn test_claim_many_batch_limit() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let user = Address::generate(&e);
+    let mut market_ids = vec![&e];
+    for market_id in 1..=21u64 {
+        market_ids.push_back(market_id);
+    }
+
+    client.claim_many(&user, &market_ids);
+}
+
+#[test]
+fn test_claim_for_pays_user_and_keeper_tip() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_platform_fee_rate(&admin, &0);
+    client.set_keeper_tip_rate(&admin, &50); // 0.5%
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let keeper = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will BTC hit $100k?"),
+            description: String::from_str(&e, "BTC prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    let paid = client.claim_for(&keeper, &user1, &market_id);
+
+    // Only the keeper authorized the call
+    let auths = e.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, keeper);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(paid, 199_0000000);
+    assert_eq!(token_view.balance(&user1), 900_0000000 + 199_0000000);
+    assert_eq!(token_view.balance(&keeper), 1_0000000);
+    assert!(client.get_user_prediction(&user1, &market_id).is_none());
+
+    // A keeper can't collect anything for a losing prediction
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.claim_for(&keeper, &user2, &market_id);
+    }));
+    assert!(result.is_err());
+    assert_eq!(token_view.balance(&keeper), 1_0000000);
+}
+
+#[test]
+#[should_panic(expected = "keeper tip rate must be between 0 and 100")]
+fn test_keeper_tip_rate_capped() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    client.set_keeper_tip_rate(&admin, &101);
+}
+
+#[test]
+fn test_market_metadata_and_category_filter() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user, &1000_0000000);
+
+    let crypto = MarketMetadata {
+        category: String::from_str(&e, "crypto"),
+        tags: vec![
+            &e,
+            String::from_str(&e, "btc"),
+            String::from_str(&e, "price"),
+        ],
+        resolution_source: String::from_str(&e, "https://www.coingecko.com/en/coins/bitcoin"),
+        image_uri: String::from_str(&e, "ipfs://bafybeibtcimage"),
+    };
+
+    let btc_market = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will BTC hit $100k?"),
+            description: String::from_str(&e, "BTC prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: crypto.clone(),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let weather_market = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    let market: Market = client.get_market(&btc_market);
+    assert_eq!(market.metadata.category, crypto.category);
+    assert_eq!(market.metadata.tags, crypto.tags);
+    assert_eq!(market.metadata.resolution_source, crypto.resolution_source);
+    assert_eq!(market.metadata.image_uri, crypto.image_uri);
+
+    let category = String::from_str(&e, "crypto");
+    assert_eq!(
+        client.get_markets_by_category(&category, &1, &10),
+        vec![&e, btc_market]
+    );
+    assert_eq!(
+        client.get_markets_by_category(&String::from_str(&e, "general"), &1, &10),
+        vec![&e, weather_market]
+    );
+    assert_eq!(client.get_markets_by_category(&category, &2, &10).len(), 0);
+
+    // The creator can re-categorize the market until someone predicts
+    let mut sports = default_metadata(&e);
+    sports.category = String::from_str(&e, "sports");
+    client.update_market_metadata(&creator, &weather_market, &sports);
+    assert_eq!(client.get_market(&weather_market).metadata.category, sports.category);
+
+    // Nobody else can
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.update_market_metadata(&user, &btc_market, &sports);
+    }));
+    assert!(result.is_err());
+
+    // And not after the first prediction
+    client.predict(&user, &btc_market, &PredictionSide::Yes, &10_0000000, &None, &None);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.update_market_metadata(&creator, &btc_market, &sports);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "too many tags")]
+fn test_create_market_too_many_tags() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let mut metadata = default_metadata(&e);
+    for _ in 0..6 {
+        metadata.tags.push_back(String::from_str(&e, "tag"));
+    }
+
+    client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: metadata.clone(),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+}
+
+#[test]
+fn test_conditional_market_refunds_when_parent_differs() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let parent_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will candidate A win?"),
+            description: String::from_str(&e, "Election"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let child_id = client.create_conditional_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "If A wins, will policy X pass?"),
+            description: String::from_str(&e, "Policy"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+        &parent_id,
+        &PredictionSide::Yes,
+    );
+
+    let child: Market = client.get_market(&child_id);
+    assert_eq!(child.condition, MarketCondition::Parent(parent_id, PredictionSide::Yes));
+
+    client.predict(&user1, &child_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &child_id, &PredictionSide::No, &300_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+
+    // The child can't resolve before its parent
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.resolve_market(&admin, &child_id, &PredictionSide::Yes, &evidence(&e));
+    }));
+    assert!(result.is_err());
+    assert!(!client.is_market_cancelled(&child_id));
+
+    // Parent resolves against the condition, cancelling the child
+    client.resolve_market(&admin, &parent_id, &PredictionSide::No, &evidence(&e));
+    assert!(client.is_market_cancelled(&child_id));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.resolve_market(&admin, &child_id, &PredictionSide::Yes, &evidence(&e));
+    }));
+    assert!(result.is_err());
+
+    // Both sides get their full stake back, without fees
+    assert_eq!(client.claim_winnings(&user1, &child_id), 100_0000000);
+    let results = client.claim_many(&user2, &vec![&e, child_id]);
+    assert_eq!(results.get(0).unwrap().status, ClaimStatus::Refunded);
+    assert_eq!(results.get(0).unwrap().amount, 300_0000000);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&user1), 1000_0000000);
+    assert_eq!(token_view.balance(&user2), 1000_0000000);
+    assert_eq!(token_view.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_conditional_market_resolves_when_parent_matches() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_platform_fee_rate(&admin, &0);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let parent_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will candidate A win?"),
+            description: String::from_str(&e, "Election"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let child_id = client.create_conditional_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "If A wins, will policy X pass?"),
+            description: String::from_str(&e, "Policy"),
+            token: token.clone(),
+            schedule: schedule(3000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+        &parent_id,
+        &PredictionSide::Yes,
+    );
+
+    client.predict(&user1, &child_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &child_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &parent_id, &PredictionSide::Yes, &evidence(&e));
+    assert!(!client.is_market_cancelled(&child_id));
+
+    e.ledger().set_timestamp(3000);
+    client.resolve_market(&admin, &child_id, &PredictionSide::Yes, &evidence(&e));
+
+    assert_eq!(client.claim_winnings(&user1, &child_id), 200_0000000);
+}
+
+#[test]
+#[should_panic(expected = "parent market already settled")]
+fn test_conditional_market_on_resolved_parent() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let parent_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will candidate A win?"),
+            description: String::from_str(&e, "Election"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &parent_id, &PredictionSide::Yes, &evidence(&e));
+
+    client.create_conditional_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "If A wins, will policy X pass?"),
+            description: String::from_str(&e, "Policy"),
+            token: token.clone(),
+            schedule: schedule(3000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+        &parent_id,
+        &PredictionSide::Yes,
+    );
+}
+
+#[test]
+fn test_roll_series() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let question = String::from_str(&e, "Will XLM close above $0.10 today?");
+    let day = 86400;
+
+    let series_id = client.create_series(
+        &creator,
+        &SeriesParams {
+            question: question.clone(),
+            description: String::from_str(&e, "Daily XLM close"),
+            token: token.clone(),
+            duration: day / 2,
+            cadence: day,
+            metadata: default_metadata(&e),
+        },
+    );
+    assert_eq!(series_id, 1);
+    assert_eq!(client.get_series_count(), 1);
+    assert_eq!(client.get_series_current_market(&series_id), None);
+
+    // The first round opens immediately
+    let first = client.roll_series(&series_id);
+    let market: Market = client.get_market(&first);
+    assert_eq!(market.question, question);
+    assert_eq!(market.creator, creator);
+    assert_eq!(market.created_at, 1000);
+    assert_eq!(market.close_time, 1000 + day / 2);
+    assert_eq!(client.get_series_current_market(&series_id), Some(first));
+
+    // Betting on the first round has closed, but the next round isn't due yet
+    e.ledger().set_timestamp(1000 + day / 2);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.roll_series(&series_id);
+    }));
+    assert!(result.is_err());
+
+    e.ledger().set_timestamp(1000 + day);
+    let second = client.roll_series(&series_id);
+    assert_eq!(client.get_market(&second).close_time, 1000 + day + day / 2);
+
+    let series = client.get_series(&series_id);
+    assert_eq!(series.rounds, 2);
+    assert_eq!(series.current_market_id, Some(second));
+    assert_eq!(series.last_rolled_at, 1000 + day);
+
+    assert_eq!(client.get_series_markets(&series_id, &0, &10), vec![&e, first, second]);
+    assert_eq!(client.get_series_markets(&series_id, &1, &10), vec![&e, second]);
+
+    // Rounds stop once the creator may no longer create markets
+    client.set_creation_policy(&admin, &CreationPolicy::AdminOnly);
+    e.ledger().set_timestamp(1000 + 2 * day);
+    let result = client.try_roll_series(&series_id);
+    assert_eq!(result, Err(Ok(CreationError::AdminOnly.into())));
+}
+
+#[test]
+#[should_panic(expected = "previous round not ended")]
+fn test_roll_series_before_round_ends() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let series_id = client.create_series(
+        &creator,
+        &SeriesParams {
+            question: String::from_str(&e, "Will XLM close above $0.10 this week?"),
+            description: String::from_str(&e, "Weekly XLM close"),
+            token: token.clone(),
+            duration: 604800,
+            cadence: 604800,
+            metadata: default_metadata(&e),
+        },
+    );
+
+    client.roll_series(&series_id);
+
+    e.ledger().set_timestamp(2000);
+    client.roll_series(&series_id);
+}
+
+#[test]
+fn test_liquidity_provider_residual_and_fee_cut() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_fee_basis(&admin, &FeeBasis::Gross);
+    client.set_lp_fee_share(&admin, &5000); // LPs get half of the 2% fee
+
+    // The gross basis charges more, so it applies after the fee timelock
+    let start = 1000 + 2 * 24 * 60 * 60;
+    e.ledger().set_timestamp(start);
+
+    let creator = Address::generate(&e);
+    let provider = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&provider, &1000);
+    token_client.mint(&user, &1000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(start + 1000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    assert_eq!(client.provide_liquidity(&provider, &market_id, &200), 200);
+
+    // Seeding doesn't count as a prediction
+    client.update_market_metadata(&creator, &market_id, &default_metadata(&e));
+
+    client.predict(&user, &market_id, &PredictionSide::Yes, &100, &None, &None);
+
+    let market: Market = client.get_market(&market_id);
+    assert_eq!(market.total_yes, 200);
+    assert_eq!(market.total_no, 100);
+    assert_eq!(market.lp_yes, 100);
+    assert_eq!(market.lp_no, 100);
+
+    let position = client.get_lp_position(&provider, &market_id).unwrap();
+    assert_eq!(position.yes, 100);
+    assert_eq!(position.no, 100);
+
+    e.ledger().set_timestamp(start + 1000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // User gets 100 * 300 / 200 = 150 gross, minus a fee of 3
+    assert_eq!(client.claim_winnings(&user, &market_id), 147);
+
+    // Provider gets the same pro-rata residual on its Yes half, plus half the fee
+    assert_eq!(client.withdraw_liquidity(&provider, &market_id), 150 + 1);
+    assert_eq!(client.get_collected_fees(&token), 2);
+
+    // Nothing more to withdraw
+    assert_eq!(client.withdraw_liquidity(&provider, &market_id), 0);
+    assert_eq!(client.sweep_dust(&market_id), 0);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&contract_id), 2);
+}
+
+#[test]
+fn test_liquidity_refunded_on_cancelled_market() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let provider = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&provider, &1000);
+
+    let parent_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will candidate A win?"),
+            description: String::from_str(&e, "Election"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let child_id = client.create_conditional_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "If A wins, will policy X pass?"),
+            description: String::from_str(&e, "Policy"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+        &parent_id,
+        &PredictionSide::Yes,
+    );
+
+    client.provide_liquidity(&provider, &child_id, &301);
+    let position = client.get_lp_position(&provider, &child_id).unwrap();
+    assert_eq!(position.yes + position.no, 301);
+
+    // Not resolved yet
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.withdraw_liquidity(&provider, &child_id);
+    }));
+    assert!(result.is_err());
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &parent_id, &PredictionSide::No, &evidence(&e));
+
+    assert_eq!(client.withdraw_liquidity(&provider, &child_id), 301);
+    assert_eq!(client.withdraw_liquidity(&provider, &child_id), 0);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&provider), 1000);
+}
+
+#[test]
+fn test_early_bonus_weighted_shares_sum_to_pot() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_platform_fee_rate(&admin, &0);
+
+    let creator = Address::generate(&e);
+    let early = Address::generate(&e);
+    let middle = Address::generate(&e);
+    let late = Address::generate(&e);
+    let loser = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    for user in [&early, &middle, &late, &loser] {
+        token_client.mint(user, &1000);
+    }
+
+    // Stakes at creation weigh double, decaying linearly to 1x at end_time
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will BTC hit $100k?"),
+            description: String::from_str(&e, "BTC prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 10000,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&early, &market_id, &PredictionSide::Yes, &100, &None, &None);
+    client.predict(&loser, &market_id, &PredictionSide::No, &300, &None, &None);
+    e.ledger().set_timestamp(1500);
+    client.predict(&middle, &market_id, &PredictionSide::Yes, &100, &None, &None);
+    e.ledger().set_timestamp(1999);
+    client.predict(&late, &market_id, &PredictionSide::Yes, &100, &None, &None);
+
+    assert_eq!(client.get_user_prediction(&early, &market_id).unwrap().weighted, 200);
+    assert_eq!(client.get_user_prediction(&middle, &market_id).unwrap().weighted, 150);
+    assert_eq!(client.get_user_prediction(&late, &market_id).unwrap().weighted, 100);
+
+    let market: Market = client.get_market(&market_id);
+    assert_eq!(market.total_yes, 300);
+    assert_eq!(market.weighted_yes, 450);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // Pot of 600 split 200:150:100 by weight
+    let early_share = client.claim_winnings(&early, &market_id);
+    let middle_share = client.claim_winnings(&middle, &market_id);
+    let late_share = client.claim_winnings(&late, &market_id);
+    assert_eq!(early_share, 266);
+    assert_eq!(middle_share, 200);
+    assert_eq!(late_share, 133);
+
+    // The rounding remainder completes the pot
+    let dust = client.sweep_dust(&market_id);
+    assert_eq!(early_share + middle_share + late_share + dust, 600);
+
+    let settlement = client.get_market_settlement(&market_id);
+    assert_eq!(settlement.distributed + settlement.dust_swept, 600);
+}
+
+#[test]
+#[should_panic(expected = "early bonus must be between 0 and 10000")]
+fn test_create_market_early_bonus_too_large() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 10001,
+            commit_reveal: false,
+        },
+    );
+}
+
+#[test]
+fn test_close_time_and_resolve_after_gates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user, &1000_0000000);
+
+    // Betting closes at kick-off, the result is known a few hours later
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will the home team win?"),
+            description: String::from_str(&e, "Football"),
+            token: token.clone(),
+            schedule: MarketSchedule {
+                close_time: 2000,
+                resolve_after: 9200,
+                resolve_deadline: Some(90000),
+            },
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.predict(&user, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    }));
+    assert!(result.is_err());
+
+    e.ledger().set_timestamp(5000);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+    }));
+    assert!(result.is_err());
+
+    e.ledger().set_timestamp(9200);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+    assert!(client.get_market(&market_id).resolved);
+}
+
+#[test]
+fn test_market_cancelled_after_resolve_deadline() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will the home team win?"),
+            description: String::from_str(&e, "Football"),
+            token: token.clone(),
+            schedule: MarketSchedule {
+                close_time: 2000,
+                resolve_after: 3000,
+                resolve_deadline: Some(4000),
+            },
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &50_0000000, &None, &None);
+
+    e.ledger().set_timestamp(4000);
+    assert!(!client.is_market_cancelled(&market_id));
+
+    // Nobody resolved the market in time
+    e.ledger().set_timestamp(4001);
+    assert!(client.is_market_cancelled(&market_id));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+    }));
+    assert!(result.is_err());
+
+    assert_eq!(client.claim_winnings(&user1, &market_id), 100_0000000);
+    assert_eq!(client.claim_winnings(&user2, &market_id), 50_0000000);
+}
+
+#[test]
+#[should_panic(expected = "resolve_after must not be before close_time")]
+fn test_create_market_resolve_before_close() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will the home team win?"),
+            description: String::from_str(&e, "Football"),
+            token: token.clone(),
+            schedule: MarketSchedule {
+                close_time: 2000,
+                resolve_after: 1500,
+                resolve_deadline: None,
+            },
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+}
+
+#[test]
+fn test_expire_market_refunds_full_stakes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_resolution_window(), 30 * 24 * 60 * 60);
+    client.set_resolution_window(&admin, &1000);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let provider = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+    token_client.mint(&provider, &1000_0000000);
+
+    // No explicit deadline, so the admin's window applies
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    assert_eq!(client.get_market(&market_id).resolve_deadline, 3000);
+
+    client.provide_liquidity(&provider, &market_id, &100_0000000);
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &300_0000000, &None, &None);
+
+    e.ledger().set_timestamp(3000);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.expire_market(&market_id);
+    }));
+    assert!(result.is_err());
+
+    // The admin never resolved the market
+    e.ledger().set_timestamp(3001);
+    client.expire_market(&market_id);
+
+    let event = MarketExpired {
+        market_id,
+        resolve_deadline: 3000,
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    assert!(client.get_market(&market_id).cancelled);
+    assert!(client.is_market_cancelled(&market_id));
+
+    // Everyone gets their full stake back, no fees
+    assert_eq!(client.get_user_volume(&user1), 100_0000000);
+    assert_eq!(client.claim_winnings(&user1, &market_id), 100_0000000);
+    assert_eq!(client.claim_winnings(&user2, &market_id), 300_0000000);
+    assert_eq!(client.withdraw_liquidity(&provider, &market_id), 100_0000000);
+
+    // Refunded stakes don't count towards the volume tiers
+    assert_eq!(client.get_user_volume(&user1), 0);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&contract_id), 0);
+    assert_eq!(client.get_collected_fees(&token), 0);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.expire_market(&market_id);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "market already resolved")]
+fn test_expire_resolved_market() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    e.ledger().set_timestamp(100_000_000);
+    client.expire_market(&market_id);
+}
+
+#[test]
+fn test_commit_reveal_prediction_flow() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let copier = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+    token_client.mint(&copier, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: MarketSchedule {
+                close_time: 2000,
+                resolve_after: 3000,
+                resolve_deadline: None,
+            },
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: true,
+        },
+    );
+
+    // Deposits hide the real stake until the reveal
+    let salt1 = BytesN::from_array(&e, &[1; 32]);
+    let salt2 = BytesN::from_array(&e, &[2; 32]);
+    let hash1 =
+        client.compute_commitment(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &salt1);
+    let hash2 =
+        client.compute_commitment(&user2, &market_id, &PredictionSide::No, &100_0000000, &salt2);
+    client.commit_prediction(&user1, &market_id, &hash1, &250_0000000);
+    client.commit_prediction(&user2, &market_id, &hash2, &100_0000000);
+
+    // A copied hash is bound to user1, so it can't be revealed by anyone else
+    client.commit_prediction(&copier, &market_id, &hash1, &100_0000000);
+
+    let market = client.get_market(&market_id);
+    assert_eq!(market.total_yes, 0);
+    assert_eq!(market.total_no, 0);
+    assert_eq!(client.get_commitment(&user1, &market_id).unwrap().deposit, 250_0000000);
+
+    // The terms can't change once hidden stakes are committed against them
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.update_market_metadata(&creator, &market_id, &default_metadata(&e));
+    }));
+    assert!(result.is_err());
+
+    // Reveals are only accepted once betting has closed
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.reveal_prediction(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &salt1);
+    }));
+    assert!(result.is_err());
+
+    e.ledger().set_timestamp(2000);
+    client.reveal_prediction(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &salt1);
+    client.reveal_prediction(&user2, &market_id, &PredictionSide::No, &100_0000000, &salt2);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.reveal_prediction(&copier, &market_id, &PredictionSide::Yes, &100_0000000, &salt1);
+    }));
+    assert!(result.is_err());
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&user1), 900_0000000); // Excess deposit returned
+    assert!(client.get_commitment(&user1, &market_id).is_none());
+
+    let market = client.get_market(&market_id);
+    assert_eq!(market.total_yes, 100_0000000);
+    assert_eq!(market.total_no, 100_0000000);
+
+    e.ledger().set_timestamp(3000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // 200 pot minus the 2% fee on the 100 profit
+    assert_eq!(client.claim_winnings(&user1, &market_id), 198_0000000);
+}
+
+#[test]
+#[should_panic(expected = "commitment mismatch")]
+fn test_reveal_with_wrong_salt() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: MarketSchedule {
+                close_time: 2000,
+                resolve_after: 3000,
+                resolve_deadline: None,
+            },
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: true,
+        },
+    );
+
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let hash =
+        client.compute_commitment(&user, &market_id, &PredictionSide::Yes, &100_0000000, &salt);
+    client.commit_prediction(&user, &market_id, &hash, &100_0000000);
+
+    e.ledger().set_timestamp(2000);
+    let wrong_salt = BytesN::from_array(&e, &[9; 32]);
+    client.reveal_prediction(&user, &market_id, &PredictionSide::Yes, &100_0000000, &wrong_salt);
+}
+
+#[test]
+fn test_unrevealed_commitment_refunded_minus_penalty() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_commit_penalty_rate(), 1000); // Default 10%
+    client.set_commit_penalty_rate(&admin, &500);
+
+    let creator = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: MarketSchedule {
+                close_time: 2000,
+                resolve_after: 3000,
+                resolve_deadline: None,
+            },
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: true,
+        },
+    );
+
+    // Regular predictions are rejected on commit-reveal markets
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.predict(&user, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    }));
+    assert!(result.is_err());
+
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let hash =
+        client.compute_commitment(&user, &market_id, &PredictionSide::Yes, &100_0000000, &salt);
+    client.commit_prediction(&user, &market_id, &hash, &100_0000000);
+    assert_eq!(client.get_commitment(&user, &market_id).unwrap().penalty_rate, 500);
+
+    // Raising the penalty later doesn't touch deposits already committed
+    client.set_commit_penalty_rate(&admin, &10000);
+
+    // Not refundable while the reveal window is open
+    e.ledger().set_timestamp(2500);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.refund_commitment(&user, &market_id);
+    }));
+    assert!(result.is_err());
+
+    e.ledger().set_timestamp(3000);
+    assert_eq!(client.refund_commitment(&user, &market_id), 95_0000000);
+    assert_eq!(client.get_collected_fees(&token), 5_0000000);
+    assert!(client.get_commitment(&user, &market_id).is_none());
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&user), 995_0000000);
+    assert_eq!(token_view.balance(&contract_id), 5_0000000);
+}
+
+#[test]
+fn test_referrer_earns_share_of_platform_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_referral_share(), 0);
+    client.set_referral_share(&admin, &2500); // 25% of the platform fee
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let referrer = Address::generate(&e);
+    let other_referrer = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(
+        &user1,
+        &market_id,
+        &PredictionSide::Yes,
+        &60_0000000,
+        &Some(referrer.clone()),
+        &None,
+    );
+    // The first referrer keeps the attribution
+    client.predict(
+        &user1,
+        &market_id,
+        &PredictionSide::Yes,
+        &40_0000000,
+        &Some(other_referrer.clone()),
+        &None,
+    );
+    client.predict(&user2, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    assert_eq!(client.get_referrer(&user1, &market_id), Some(referrer.clone()));
+    assert_eq!(client.get_referrer(&user2, &market_id), None);
+
+    let stats = client.get_referrer_stats(&referrer, &token);
+    assert_eq!(stats.volume, 100_0000000);
+    assert_eq!(stats.referred, 2);
+    assert_eq!(stats.earned, 0);
+    assert_eq!(client.get_referrer_stats(&other_referrer, &token).volume, 0);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // 100 profit, 2 fee: 0.5 to the referrer, 1.5 to the platform
+    assert_eq!(client.claim_winnings(&user1, &market_id), 198_0000000);
+    assert_eq!(client.get_referrer_stats(&referrer, &token).earned, 5000000);
+    assert_eq!(client.get_collected_fees(&token), 1_5000000);
+
+    assert_eq!(client.claim_referral_rewards(&referrer, &token), 5000000);
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&referrer), 5000000);
+    assert_eq!(client.get_referrer_stats(&referrer, &token).claimed, 5000000);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.claim_referral_rewards(&referrer, &token);
+    }));
+    assert!(result.is_err());
+
+    // Refunded stakes no longer count towards the referrer's stats
+    client.set_resolution_window(&admin, &1000);
+    let expiring_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it snow tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(3000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    client.predict(
+        &user2,
+        &expiring_id,
+        &PredictionSide::Yes,
+        &50_0000000,
+        &Some(referrer.clone()),
+        &None,
+    );
+    let stats = client.get_referrer_stats(&referrer, &token);
+    assert_eq!((stats.volume, stats.referred), (150_0000000, 3));
+
+    e.ledger().set_timestamp(4001);
+    client.expire_market(&expiring_id);
+    assert_eq!(client.claim_winnings(&user2, &expiring_id), 50_0000000);
+
+    let stats = client.get_referrer_stats(&referrer, &token);
+    assert_eq!((stats.volume, stats.referred), (100_0000000, 2));
+    assert_eq!(client.get_referrer(&user2, &expiring_id), None);
+}
+
+#[test]
+#[should_panic(expected = "referral share must be between 0 and 5000 (0-50%)")]
+fn test_referral_share_above_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_referral_share(&admin, &5001);
+}
+
+#[test]
+fn test_fee_tiers_by_volume_and_stake() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    client.set_fee_tiers(
+        &admin,
+        &FeeTierKind::Volume,
+        &vec![
+            &e,
+            FeeTier { threshold: 100_0000000, fee_rate: 150 },
+            FeeTier { threshold: 500_0000000, fee_rate: 100 },
+        ],
+    );
+    client.set_fee_tiers(
+        &admin,
+        &FeeTierKind::Stake,
+        &vec![&e, FeeTier { threshold: 1000_0000000, fee_rate: 50 }],
+    );
+    assert_eq!(client.get_fee_tiers(&FeeTierKind::Volume).len(), 2);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let governance_token = create_token(&e, &admin);
+    client.set_staking_token(&admin, &governance_token);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+    StellarAssetClient::new(&e, &governance_token).mint(&user1, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    // Below the first tier the flat rate applies
+    assert_eq!(client.get_effective_fee_rate(&user1), 200);
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &50_0000000, &None, &None);
+    assert_eq!(client.get_effective_fee_rate(&user1), 200);
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &50_0000000, &None, &None);
+    assert_eq!(client.get_user_volume(&user1), 100_0000000);
+    assert_eq!(client.get_effective_fee_rate(&user1), 150);
+
+    client.predict(&user2, &market_id, &PredictionSide::No, &500_0000000, &None, &None);
+    assert_eq!(client.get_effective_fee_rate(&user2), 100);
+
+    // Staking reaches a better tier than volume alone
+    client.stake(&user1, &1000_0000000);
+    assert_eq!(client.get_staked_balance(&user1), 1000_0000000);
+    assert_eq!(client.get_effective_fee_rate(&user1), 50);
+
+    // Staked tokens can't be moved on to another account right away
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.unstake(&user1, &1_0000000);
+    }));
+    assert!(result.is_err());
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // 500 profit at 0.5%
+    assert_eq!(client.claim_winnings(&user1, &market_id), 597_5000000);
+    assert_eq!(client.get_collected_fees(&token), 2_5000000);
+
+    e.ledger().set_timestamp(1000 + 7 * 24 * 60 * 60);
+    client.unstake(&user1, &1_0000000);
+    assert_eq!(client.get_effective_fee_rate(&user1), 150);
+}
+
+#[test]
+#[should_panic(expected = "tier thresholds must be positive and increasing")]
+fn test_fee_tiers_must_be_ascending() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_fee_tiers(
+        &admin,
+        &FeeTierKind::Volume,
+        &vec![
+            &e,
+            FeeTier { threshold: 500_0000000, fee_rate: 100 },
+            FeeTier { threshold: 100_0000000, fee_rate: 150 },
+        ],
+    );
+}
+
+#[test]
+fn test_fee_on_profit_only_for_one_sided_pool() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_fee_basis(), FeeBasis::Profit);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    // Nobody bet against Yes
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::Yes, &300_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // Winners get their stake back without paying a fee on it
+    assert_eq!(client.claim_winnings(&user1, &market_id), 100_0000000);
+
+    // A gross basis charges the returned stake too, once the switch has
+    // waited out the fee timelock
+    client.set_fee_basis(&admin, &FeeBasis::Gross);
+    let effective_at = 2000 + 2 * 24 * 60 * 60;
+    let event = FeeBasisChangeScheduled {
+        basis: FeeBasis::Gross,
+        effective_at,
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+    assert_eq!(client.get_fee_basis(), FeeBasis::Profit);
+    assert_eq!(client.get_pending_fee_basis().unwrap().effective_at, effective_at);
+
+    e.ledger().set_timestamp(effective_at);
+    assert_eq!(client.get_fee_basis(), FeeBasis::Gross);
+    assert_eq!(client.get_pending_fee_basis(), None);
+
+    // Markets keep the basis they were created with
+    assert_eq!(client.get_market(&market_id).fee_basis, FeeBasis::Profit);
+    assert_eq!(client.claim_winnings(&user2, &market_id), 300_0000000);
+
+    let gross_market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(effective_at + 1000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    client.predict(&user2, &gross_market_id, &PredictionSide::Yes, &300_0000000, &None, &None);
+
+    e.ledger().set_timestamp(effective_at + 1000);
+    client.resolve_market(&admin, &gross_market_id, &PredictionSide::Yes, &evidence(&e));
+    assert_eq!(client.claim_winnings(&user2, &gross_market_id), 294_0000000);
+    assert_eq!(client.get_collected_fees(&token), 6_0000000);
+
+    // Going back to the cheaper basis applies immediately
+    client.set_fee_basis(&admin, &FeeBasis::Profit);
+    assert_eq!(client.get_fee_basis(), FeeBasis::Profit);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&user1), 1000_0000000);
+}
+
+#[test]
+fn test_fee_on_profit_two_sided_pool() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &300_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // 400 pot, 100 profit, 2% of the profit
+    assert_eq!(client.claim_winnings(&user1, &market_id), 398_0000000);
+    assert_eq!(client.get_collected_fees(&token), 2_0000000);
+}
+
+#[test]
+fn test_fees_forwarded_to_treasury() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let ops = Address::generate(&e);
+    let insurance = Address::generate(&e);
+    let treasury_id = e.register(treasury::Treasury, ());
+    let treasury_client = treasury::TreasuryClient::new(&e, &treasury_id);
+    treasury_client.initialize(
+        &admin,
+        &vec![
+            &e,
+            treasury::Recipient { address: ops.clone(), share: 7000 },
+            treasury::Recipient { address: insurance.clone(), share: 3000 },
+        ],
+    );
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.forward_fees(&token);
+    }));
+    assert!(result.is_err());
+
+    client.set_treasury(&admin, &treasury_id);
+    assert_eq!(client.get_treasury(), Some(treasury_id.clone()));
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // The 2 fee leaves the market contract with the claim
+    assert_eq!(client.claim_winnings(&user1, &market_id), 198_0000000);
+    assert_eq!(client.get_collected_fees(&token), 0);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&contract_id), 0);
+    assert_eq!(token_view.balance(&treasury_id), 2_0000000);
+    assert_eq!(treasury_client.get_balance(&token), 2_0000000);
+    assert_eq!(treasury_client.get_claimable(&ops, &token), 1_4000000);
+    assert_eq!(treasury_client.get_claimable(&insurance, &token), 6000000);
+
+    assert_eq!(treasury_client.withdraw(&ops, &token), 1_4000000);
+    assert_eq!(token_view.balance(&ops), 1_4000000);
+
+    // Nothing left to forward
+    assert_eq!(client.forward_fees(&token), 0);
+}
+
+#[test]
+fn test_claim_succeeds_when_treasury_distribution_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    // Not a treasury contract, so `distribute` fails
+    let treasury = Address::generate(&e);
+    client.set_treasury(&admin, &treasury);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // The winner is paid and the fee still reaches the treasury address
+    assert_eq!(client.claim_winnings(&user1, &market_id), 198_0000000);
+    assert_eq!(client.get_collected_fees(&token), 0);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&treasury), 2_0000000);
+}
+
+#[test]
+fn test_insurance_payout_capped_per_incident() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_platform_fee_rate(&admin, &1000); // 10%
+    client.set_insurance_share(&admin, &5000); // Half of the platform fee
+    client.set_insurance_cap(&admin, &1000); // 10% of a market's pool per incident
+
+    // The fee increase applies after its timelock
+    let start = 1000 + 2 * 24 * 60 * 60;
+    e.ledger().set_timestamp(start);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+    token_client.mint(&user3, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(start + 1000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &200_0000000, &None, &None);
+    client.predict(&user3, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    e.ledger().set_timestamp(start + 1000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    // 300 profit, 30 fee split between the platform and the insurance fund
+    assert_eq!(client.claim_winnings(&user1, &market_id), 370_0000000);
+    assert_eq!(client.get_insurance_fund(&token), 15_0000000);
+    assert_eq!(client.get_collected_fees(&token), 15_0000000);
+
+    // The outcome turns out to be wrong, No voters are compensated
+    let paid = client.insurance_payout(
+        &admin,
+        &market_id,
+        &vec![
+            &e,
+            Compensation { recipient: user2.clone(), amount: 10_0000000 },
+            Compensation { recipient: user3.clone(), amount: 5_0000000 },
+        ],
+    );
+    assert_eq!(paid, 15_0000000);
+
+    let event = InsurancePaid {
+        market_id,
+        recipient: user3.clone(),
+        amount: 5_0000000,
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&user2), 810_0000000);
+    assert_eq!(token_view.balance(&user3), 905_0000000);
+    assert_eq!(client.get_insurance_fund(&token), 0);
+    assert_eq!(client.get_market_settlement(&market_id).insurance_paid, 15_0000000);
+
+    // The fund is empty
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.insurance_payout(
+            &admin,
+            &market_id,
+            &vec![&e, Compensation { recipient: user2.clone(), amount: 1 }],
+        );
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "insurance cap exceeded")]
+fn test_insurance_payout_above_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_platform_fee_rate(&admin, &1000);
+    client.set_insurance_share(&admin, &10000);
+    client.set_insurance_cap(&admin, &500); // 5% of a market's pool per incident
+
+    // The fee increase applies after its timelock
+    let start = 1000 + 2 * 24 * 60 * 60;
+    e.ledger().set_timestamp(start);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(start + 1000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &300_0000000, &None, &None);
+
+    e.ledger().set_timestamp(start + 1000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+    client.claim_winnings(&user1, &market_id);
+
+    // 30 in the fund, but only 20 payable for this market
+    assert_eq!(client.get_insurance_fund(&token), 30_0000000);
+    client.insurance_payout(
+        &admin,
+        &market_id,
+        &vec![&e, Compensation { recipient: user2.clone(), amount: 20_0000001 }],
+    );
+}
+
+#[test]
+fn test_governance_vote_resolves_disputed_market() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let holder1 = Address::generate(&e);
+    let holder2 = Address::generate(&e);
+    let holder3 = Address::generate(&e);
+    let late_buyer = Address::generate(&e);
+    let governance_id = e.register(
+        governance::GovernanceToken,
+        (
+            &admin,
+            7_u32,
+            String::from_str(&e, "Governance"),
+            String::from_str(&e, "GOV"),
+        ),
+    );
+    let governance_client = governance::GovernanceTokenClient::new(&e, &governance_id);
+    governance_client.set_market_contract(&contract_id);
+    governance_client.set_voting_period(&1000);
+    governance_client.set_quorum(&500);
+    governance_client.set_proposal_threshold(&100);
+    governance_client.mint(&holder1, &400);
+    governance_client.mint(&holder2, &300);
+    governance_client.mint(&holder3, &200);
+
+    client.set_resolver(&admin, &governance_id);
+    assert_eq!(client.get_resolver(), Some(governance_id.clone()));
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    // The outcome is disputed, holders vote on it
+    e.ledger().set_timestamp(2000);
+    governance_client.open_dispute(&holder3, &market_id);
+
+    e.ledger().set_timestamp(2500);
+    assert_eq!(governance_client.vote(&holder1, &market_id, &governance::PredictionSide::No), 400);
+    assert_eq!(governance_client.vote(&holder2, &market_id, &governance::PredictionSide::Yes), 300);
+    assert_eq!(governance_client.vote(&holder3, &market_id, &governance::PredictionSide::No), 200);
+
+    // Tokens bought during the vote carry no weight
+    governance_client.mint(&late_buyer, &1000);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        governance_client.vote(&late_buyer, &market_id, &governance::PredictionSide::Yes);
+    }));
+    assert!(result.is_err());
+
+    e.ledger().set_timestamp(3000);
+    assert_eq!(
+        governance_client.execute_dispute(&market_id),
+        Some(governance::PredictionSide::No)
+    );
+
+    let market = client.get_market(&market_id);
+    assert!(market.resolved);
+    assert_eq!(client.get_winning_side(&market_id), Some(PredictionSide::No));
+    assert_eq!(
+        governance_client.get_dispute(&market_id).unwrap().status,
+        governance::DisputeStatus::Executed
+    );
+    assert_eq!(client.get_resolution(&market_id).unwrap().resolver, governance_id);
+
+    assert_eq!(client.claim_winnings(&user2, &market_id), 198_0000000);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        governance_client.execute_dispute(&market_id);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_governance_vote_overrides_contested_resolution() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let holder1 = Address::generate(&e);
+    let holder2 = Address::generate(&e);
+    let governance_id = e.register(
+        governance::GovernanceToken,
+        (
+            &admin,
+            7_u32,
+            String::from_str(&e, "Governance"),
+            String::from_str(&e, "GOV"),
+        ),
+    );
+    let governance_client = governance::GovernanceTokenClient::new(&e, &governance_id);
+    governance_client.set_market_contract(&contract_id);
+    governance_client.set_voting_period(&1000);
+    governance_client.set_quorum(&500);
+    governance_client.set_proposal_threshold(&100);
+    governance_client.mint(&holder1, &400);
+    governance_client.mint(&holder2, &400);
+    client.set_resolver(&admin, &governance_id);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let params = MarketParams {
+        question: String::from_str(&e, "Will it rain tomorrow?"),
+        description: String::from_str(&e, "Weather prediction"),
+        token: token.clone(),
+        schedule: schedule(2000),
+        metadata: default_metadata(&e),
+        early_bonus: 0,
+        commit_reveal: false,
+    };
+    let market_id = client.create_market(&creator, &params);
+    let upheld_id = client.create_market(&creator, &params);
+    let parent_id = client.create_market(&creator, &params);
+    let child_id =
+        client.create_conditional_market(&creator, &params, &parent_id, &PredictionSide::Yes);
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    e.ledger().set_timestamp(2000);
+    for id in [market_id, upheld_id, parent_id, child_id] {
+        client.resolve_market(&admin, &id, &PredictionSide::Yes, &evidence(&e));
+    }
+
+    // A conditional market already paying out on the parent's outcome pins it
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        governance_client.open_dispute(&holder1, &parent_id);
+    }));
+    assert!(result.is_err());
+
+    // Holders contest the admin's outcome, which freezes claims
+    governance_client.open_dispute(&holder1, &market_id);
+
+    let event = ResolutionContested {
+        market_id,
+        resolver: governance_id.clone(),
+        winning_side: PredictionSide::Yes,
+    };
+    // Published just before the governance token's own DisputeOpened event
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 2..events.len() - 1),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    assert!(governance_client.get_dispute(&market_id).unwrap().contested);
+    assert!(client.is_resolution_contested(&market_id));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.claim_winnings(&user1, &market_id);
+    }));
+    assert!(result.is_err());
+
+    // A tied vote upholds the outcome for good
+    governance_client.open_dispute(&holder2, &upheld_id);
+    e.ledger().set_timestamp(2500);
+    governance_client.vote(&holder1, &upheld_id, &governance::PredictionSide::No);
+    governance_client.vote(&holder2, &upheld_id, &governance::PredictionSide::Yes);
+    governance_client.vote(&holder1, &market_id, &governance::PredictionSide::No);
+    governance_client.vote(&holder2, &market_id, &governance::PredictionSide::No);
+
+    e.ledger().set_timestamp(3000);
+    assert_eq!(governance_client.execute_dispute(&upheld_id), None);
+    assert!(!client.is_resolution_contested(&upheld_id));
+    assert_eq!(client.get_winning_side(&upheld_id), Some(PredictionSide::Yes));
+    assert_eq!(
+        governance_client.get_dispute(&upheld_id).unwrap().status,
+        governance::DisputeStatus::Upheld
+    );
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        governance_client.open_dispute(&holder2, &upheld_id);
+    }));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.contest_resolution(&governance_id, &upheld_id);
+    }));
+    assert!(result.is_err());
+
+    // The majority replaces the contested outcome
+    assert_eq!(
+        governance_client.execute_dispute(&market_id),
+        Some(governance::PredictionSide::No)
+    );
+    assert_eq!(client.get_winning_side(&market_id), Some(PredictionSide::No));
+    assert_eq!(client.get_resolution(&market_id).unwrap().resolver, governance_id);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.claim_winnings(&user1, &market_id);
+    }));
+    assert!(result.is_err());
+    assert_eq!(client.claim_winnings(&user2, &market_id), 198_0000000);
+
+    // Nothing paid out, or already contested, can be contested
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.contest_resolution(&governance_id, &market_id);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_multisig_threshold_executes_action() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let signer1 = Address::generate(&e);
+    let signer2 = Address::generate(&e);
+    let signer3 = Address::generate(&e);
+    let outsider = Address::generate(&e);
+    client.set_signers(
+        &admin,
+        &vec![&e, signer1.clone(), signer2.clone(), signer3.clone()],
+        &2,
+    );
+    assert_eq!(client.get_threshold(), 2);
+
+    // A single signature is no longer enough
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_platform_fee_rate(&admin, &500);
+    }));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.update_admin(&admin, &outsider);
+    }));
+    assert!(result.is_err());
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.propose_action(&outsider, &AdminAction::SetPlatformFeeRate(500));
+    }));
+    assert!(result.is_err());
+
+    let proposal_id = client.propose_action(&signer1, &AdminAction::SetPlatformFeeRate(500));
+    assert_eq!(client.get_platform_fee_rate(), 200);
+
+    let pending = client.get_pending_proposals(&1, &10);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().approvals, vec![&e, signer1.clone()]);
+
+    // The proposer already approved
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.approve_action(&signer1, &proposal_id);
+    }));
+    assert!(result.is_err());
+
+    client.approve_action(&signer2, &proposal_id);
+
+    let event = ActionExecuted { proposal_id };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    assert_eq!(client.get_pending_fee_rate().unwrap().rate, 500);
+    assert!(client.get_proposal(&proposal_id).executed);
+    assert_eq!(client.get_pending_proposals(&1, &10).len(), 0);
+
+    // An executed proposal can't be replayed
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.approve_action(&signer3, &proposal_id);
+    }));
+    assert!(result.is_err());
+
+    // Admin resolution goes through the signers too
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    e.ledger().set_timestamp(2000);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+    }));
+    assert!(result.is_err());
+
+    let proposal_id = client.propose_action(
+        &signer3,
+        &AdminAction::ResolveMarket(market_id, PredictionSide::No, evidence(&e)),
+    );
+    client.approve_action(&signer1, &proposal_id);
+    assert!(client.get_market(&market_id).resolved);
+    assert_eq!(client.get_winning_side(&market_id), Some(PredictionSide::No));
+
+    // The admin can't get around the signers by making themselves resolver
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_resolver(&admin, &admin);
+    }));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_treasury(&admin, &admin);
+    }));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.sweep_unclaimed(&admin, &market_id, &admin);
+    }));
+    assert!(result.is_err());
+    assert_eq!(client.get_resolver(), None);
+
+    let resolver = Address::generate(&e);
+    let proposal_id = client.propose_action(&signer1, &AdminAction::SetResolver(resolver.clone()));
+    client.approve_action(&signer2, &proposal_id);
+    assert_eq!(client.get_resolver(), Some(resolver));
+
+    let proposal_id = client.propose_action(&signer2, &AdminAction::UpdateAdmin(outsider.clone()));
+    client.approve_action(&signer3, &proposal_id);
+    assert_eq!(client.get_pending_admin(), Some(outsider.clone()));
+    client.accept_admin(&outsider);
+    assert_eq!(client.get_admin(), outsider);
+
+    // Settings that move user funds or gate access need the signers as well
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_commit_penalty_rate(&outsider, &10000);
+    }));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_moderator(&outsider, &outsider, &true);
+    }));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_creation_policy(&outsider, &CreationPolicy::AdminOnly);
+    }));
+    assert!(result.is_err());
+
+    let proposal_id = client.propose_action(&signer1, &AdminAction::SetCommitPenaltyRate(1000));
+    client.approve_action(&signer2, &proposal_id);
+    assert_eq!(client.get_commit_penalty_rate(), 1000);
+
+    let proposal_id = client.propose_action(
+        &signer2,
+        &AdminAction::SetCreationPolicy(CreationPolicy::AdminOnly),
+    );
+    client.approve_action(&signer3, &proposal_id);
+    assert_eq!(client.get_creation_policy(), CreationPolicy::AdminOnly);
+}
+
+#[test]
+#[should_panic(expected = "proposal expired")]
+fn test_multisig_proposal_expires() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let signer1 = Address::generate(&e);
+    let signer2 = Address::generate(&e);
+    client.set_signers(&admin, &vec![&e, signer1.clone(), signer2.clone()], &2);
+
+    let proposal_id = client.propose_action(&signer1, &AdminAction::SetPlatformFeeRate(500));
+    assert_eq!(client.get_proposal(&proposal_id).expires_at, 1000 + 7 * 24 * 60 * 60);
+
+    e.ledger().set_timestamp(1000 + 7 * 24 * 60 * 60);
+    assert_eq!(client.get_pending_proposals(&1, &10).len(), 0);
+    client.approve_action(&signer2, &proposal_id);
+}
+
+#[test]
+fn test_multisig_signer_change_voids_pending_proposals() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let signer1 = Address::generate(&e);
+    let signer2 = Address::generate(&e);
+    let signer3 = Address::generate(&e);
+    client.set_signers(&admin, &vec![&e, signer1.clone(), signer2.clone()], &2);
+
+    // Only the signers can change the signer set now
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_signers(&admin, &vec![&e, signer1.clone()], &1);
+    }));
+    assert!(result.is_err());
+
+    let stale_id = client.propose_action(&signer1, &AdminAction::SetPlatformFeeRate(1000));
+
+    let proposal_id = client.propose_action(
+        &signer2,
+        &AdminAction::SetSigners(vec![&e, signer2.clone(), signer3.clone()], 2),
+    );
+    client.approve_action(&signer1, &proposal_id);
+    assert_eq!(client.get_signers(), vec![&e, signer2.clone(), signer3.clone()]);
+
+    // Approvals gathered under the old signer set can't be completed
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.approve_action(&signer3, &stale_id);
+    }));
+    assert!(result.is_err());
+    assert_eq!(client.get_platform_fee_rate(), 200);
+
+    // Removed signers can't propose
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.propose_action(&signer1, &AdminAction::SetPlatformFeeRate(1000));
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let typo = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    // A mistaken nomination can be replaced before anyone accepts
+    client.update_admin(&admin, &typo);
+    assert_eq!(client.get_pending_admin(), Some(typo.clone()));
+    assert_eq!(client.get_admin(), admin);
+
+    client.update_admin(&admin, &new_admin);
+
+    let event = AdminTransferProposed {
+        admin: admin.clone(),
+        new_admin: new_admin.clone(),
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.accept_admin(&typo);
+    }));
+    assert!(result.is_err());
+
+    client.accept_admin(&new_admin);
+
+    let event = AdminTransferred {
+        previous_admin: admin.clone(),
+        new_admin: new_admin.clone(),
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    // The old admin lost its rights
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_claim_window(&admin, &100);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_fee_increase_timelock() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &100_0000000, &None, &None);
+
+    client.set_platform_fee_rate(&admin, &1000);
+
+    let effective_at = 1000 + 2 * 24 * 60 * 60;
+    let event = FeeRateIncreaseScheduled {
+        rate: 1000,
+        effective_at,
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    // Open markets keep the rate they were created with
+    assert_eq!(client.get_market(&market_id).fee_rate, 200);
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+    assert_eq!(client.claim_winnings(&user1, &market_id), 198_0000000);
+
+    e.ledger().set_timestamp(effective_at - 1);
+    assert_eq!(client.get_effective_fee_rate(&user1), 200);
+    e.ledger().set_timestamp(effective_at);
+    assert_eq!(client.get_effective_fee_rate(&user1), 1000);
+
+    // A new increase restarts the clock from the applied rate
+    client.set_platform_fee_rate(&admin, &1500);
+    assert_eq!(client.get_platform_fee_rate(), 1000);
+    assert_eq!(client.get_pending_fee_rate().unwrap().effective_at, effective_at * 2 - 1000);
+
+    // A decrease cancels the pending increase
+    client.set_platform_fee_rate(&admin, &300);
+    assert_eq!(client.get_platform_fee_rate(), 300);
+    assert_eq!(client.get_pending_fee_rate(), None);
+    e.ledger().set_timestamp(effective_at * 3);
+    assert_eq!(client.get_platform_fee_rate(), 300);
+}
+
+#[test]
+fn test_creation_policy_modes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_creation_policy(), CreationPolicy::Open);
+
+    let creator = Address::generate(&e);
+    let trusted = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let question = String::from_str(&e, "Will it rain tomorrow?");
+    let description = String::from_str(&e, "Weather prediction");
+
+    client.set_creation_policy(&admin, &CreationPolicy::AdminOnly);
+    let result = client.try_create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    assert_eq!(result, Err(Ok(CreationError::AdminOnly.into())));
+    client.create_market(
+        &admin,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.set_creation_policy(&admin, &CreationPolicy::Allowlist);
+    client.set_allowlisted(&admin, &trusted, &true);
+    assert!(client.is_allowlisted(&trusted));
+
+    let result = client.try_create_series(
+        &creator,
+        &SeriesParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            duration: 100,
+            cadence: 100,
+            metadata: default_metadata(&e),
+        },
+    );
+    assert_eq!(result, Err(Ok(CreationError::NotAllowlisted.into())));
+    client.create_market(
+        &trusted,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.set_allowlisted(&admin, &trusted, &false);
+    let result = client.try_create_market(
+        &trusted,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    assert_eq!(result, Err(Ok(CreationError::NotAllowlisted.into())));
+
+    // Open again, so anyone can create
+    client.set_creation_policy(&admin, &CreationPolicy::Open);
+    client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    assert_eq!(client.get_market_count(), 3);
+}
+
+#[test]
+fn test_creator_reputation_threshold() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let question = String::from_str(&e, "Will it rain tomorrow?");
+    let description = String::from_str(&e, "Weather prediction");
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.set_min_creator_reputation(&admin, &1);
+    let result = client.try_create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    assert_eq!(result, Err(Ok(CreationError::InsufficientReputation.into())));
+
+    // A resolved market earns the creator reputation
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+    assert_eq!(client.get_creator_reputation(&creator), 1);
+
+    client.create_market(
+        &creator,
+        &MarketParams {
+            question: question.clone(),
+            description: description.clone(),
+            token: token.clone(),
+            schedule: schedule(3000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    assert_eq!(client.get_market_count(), 2);
+}
+
+#[test]
+fn test_flag_and_delist_market_refunds_stakes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let moderator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    let other_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it snow tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &300_0000000, &None, &None);
+
+    let reason = String::from_str(&e, "misleading question");
+    assert_eq!(client.flag_market(&user1, &market_id, &reason), 1);
+    assert_eq!(client.flag_market(&user2, &market_id, &reason), 2);
+
+    let event = MarketFlagged {
+        market_id,
+        user: user2.clone(),
+        reason: reason.clone(),
+        flags: 2,
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    assert_eq!(client.get_flag_count(&market_id), 2);
+    assert_eq!(
+        client.get_flag(&market_id, &user1),
+        Some(MarketFlag {
+            reason: reason.clone(),
+            flagged_at: 1000,
+        })
+    );
+
+    // One flag per user
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.flag_market(&user1, &market_id, &reason);
+    }));
+    assert!(result.is_err());
+
+    client.set_moderator(&admin, &moderator, &true);
+    assert!(client.is_moderator(&moderator));
+
+    e.ledger().set_timestamp(1500);
+    let decision = String::from_str(&e, "violates listing rules");
+    client.delist_market(&moderator, &market_id, &decision);
+
+    let event = MarketDelisted {
+        market_id,
+        moderator: moderator.clone(),
+        reason: decision.clone(),
+        refunded: true,
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    assert_eq!(
+        client.get_delisting(&market_id),
+        Some(Delisting {
+            moderator: moderator.clone(),
+            reason: decision,
+            delisted_at: 1500,
+        })
+    );
+    assert_eq!(client.get_delisting(&other_id), None);
+
+    // Hidden from list views
+    assert_eq!(
+        client.get_markets_by_category(&String::from_str(&e, "general"), &1, &10),
+        vec![&e, other_id]
+    );
+
+    // Everyone gets their full stake back
+    assert!(client.get_market(&market_id).delisted);
+    assert!(client.is_market_cancelled(&market_id));
+    assert_eq!(client.claim_winnings(&user1, &market_id), 100_0000000);
+    assert_eq!(client.claim_winnings(&user2, &market_id), 300_0000000);
+
+    // A revoked moderator can't delist
+    client.set_moderator(&admin, &moderator, &false);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.delist_market(&moderator, &other_id, &reason);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "not authorized")]
+fn test_delist_market_unauthorized() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    // Creators can't delist their own markets
+    client.delist_market(&creator, &market_id, &String::from_str(&e, "changed my mind"));
+}
+
+#[test]
+fn test_resolution_evidence_stored_with_outcome() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let resolver = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+    assert_eq!(client.get_resolution(&market_id), None);
+
+    client.set_resolver(&admin, &resolver);
+
+    e.ledger().set_timestamp(2500);
+    client.resolve_market(&resolver, &market_id, &PredictionSide::No, &evidence(&e));
+
+    let event = MarketResolved {
+        market_id,
+        winning_side: PredictionSide::No,
+        resolver: resolver.clone(),
+        evidence: evidence(&e),
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    assert_eq!(
+        client.get_resolution(&market_id),
+        Some(Resolution {
+            winning_side: PredictionSide::No,
+            resolver,
+            evidence: evidence(&e),
+            resolved_at: 2500,
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "justification too long")]
+fn test_resolution_justification_too_long() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    let mut evidence = evidence(&e);
+    evidence.justification = String::from_bytes(&e, &[b'a'; 257]);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence);
+}
+
+#[test]
+fn test_odds_and_payout_quotes_match_claim() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+    token_client.mint(&user3, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    // An empty pool is even
+    assert_eq!(client.get_odds(&market_id), MarketOdds { yes: 5000, no: 5000 });
+
+    client.predict(&user1, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    client.predict(&user2, &market_id, &PredictionSide::No, &300_0000000, &None, &None);
+    assert_eq!(client.get_odds(&market_id), MarketOdds { yes: 2500, no: 7500 });
+
+    // 100 more on Yes: 100/200 of a 500 pot, 2% fee on the 150 profit
+    let quote = client.quote_payout(&market_id, &PredictionSide::Yes, &100_0000000);
+    assert_eq!(quote, 247_0000000);
+
+    client.predict(&user3, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+    assert_eq!(client.get_odds(&market_id), MarketOdds { yes: 4000, no: 6000 });
+    assert_eq!(client.get_claimable(&user3, &market_id), 0);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+
+    assert_eq!(client.get_claimable(&user2, &market_id), 0);
+    assert_eq!(client.get_claimable(&user3, &market_id), quote);
+    assert_eq!(client.claim_winnings(&user3, &market_id), quote);
+    assert_eq!(client.get_claimable(&user3, &market_id), 0);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.quote_payout(&market_id, &PredictionSide::Yes, &100_0000000);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_predict_slippage_and_deadline() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+    e.ledger().set_sequence_number(100);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+
+    let creator = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&user1, &1000_0000000);
+    token_client.mint(&user2, &1000_0000000);
+    token_client.mint(&user3, &1000_0000000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user1, &market_id, &PredictionSide::No, &300_0000000, &None, &None);
+
+    let quote = client.quote_payout(&market_id, &PredictionSide::Yes, &100_0000000);
+    assert_eq!(quote, 394_0000000);
+
+    // Someone else backs Yes before user3's bet lands
+    client.predict(&user2, &market_id, &PredictionSide::Yes, &100_0000000, &None, &None);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.predict(
+            &user3,
+            &market_id,
+            &PredictionSide::Yes,
+            &100_0000000,
+            &None,
+            &Some(PredictionLimits {
+                min_payout: quote,
+                deadline: 0,
+            }),
+        );
+    }));
+    assert!(result.is_err());
+    assert!(client.get_user_prediction(&user3, &market_id).is_none());
+
+    // A looser bound still accepts the moved pool
+    client.predict(
+        &user3,
+        &market_id,
+        &PredictionSide::Yes,
+        &100_0000000,
+        &None,
+        &Some(PredictionLimits {
+            min_payout: 247_0000000,
+            deadline: 0,
+        }),
+    );
+    assert_eq!(client.get_market(&market_id).total_yes, 200_0000000);
+
+    // Stale transactions are rejected once the deadline ledger has passed
+    e.ledger().set_sequence_number(150);
+    let limits = PredictionLimits {
+        min_payout: 0,
+        deadline: 150,
+    };
+    client.predict(
+        &user1,
+        &market_id,
+        &PredictionSide::No,
+        &10_0000000,
+        &None,
+        &Some(limits.clone()),
+    );
+
+    e.ledger().set_sequence_number(151);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.predict(
+            &user1,
+            &market_id,
+            &PredictionSide::No,
+            &10_0000000,
+            &None,
+            &Some(limits),
+        );
+    }));
+    assert!(result.is_err());
+    assert_eq!(client.get_market(&market_id).total_no, 310_0000000);
+}
+
+#[test]
+fn test_claim_for_refund_pays_no_keeper_tip() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_keeper_tip_rate(&admin, &100); // 1%
+
+    let creator = Address::generate(&e);
+    let user = Address::generate(&e);
+    let keeper = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    StellarAssetClient::new(&e, &token).mint(&user, &10000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&user, &market_id, &PredictionSide::Yes, &10000, &None, &None);
+    client.delist_market(&admin, &market_id, &String::from_str(&e, "violates listing rules"));
+
+    // The user gets their whole stake back, the keeper nothing
+    assert_eq!(client.claim_for(&keeper, &user, &market_id), 10000);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&user), 10000);
+    assert_eq!(token_view.balance(&keeper), 0);
+}
+
+#[test]
+fn test_lp_fees_withdrawable_after_claim_deadline() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_fee_basis(&admin, &FeeBasis::Gross);
+    client.set_lp_fee_share(&admin, &5000); // LPs get half of the 2% fee
+    client.set_claim_window(&admin, &500);
+
+    // The gross basis charges more, so it applies after the fee timelock
+    let start = 1000 + 2 * 24 * 60 * 60;
+    e.ledger().set_timestamp(start);
+
+    let creator = Address::generate(&e);
+    let provider = Address::generate(&e);
+    let user = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&provider, &1000);
+    token_client.mint(&user, &1000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will it rain tomorrow?"),
+            description: String::from_str(&e, "Weather prediction"),
+            token: token.clone(),
+            schedule: schedule(start + 1000),
+            metadata: default_metadata(&e),
+            early_bonus: 0,
+            commit_reveal: false,
+        },
+    );
+
+    client.provide_liquidity(&provider, &market_id, &200);
+    client.predict(&user, &market_id, &PredictionSide::Yes, &100, &None, &None);
+
+    e.ledger().set_timestamp(start + 1000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::Yes, &evidence(&e));
+    assert_eq!(client.claim_winnings(&user, &market_id), 147);
+
+    // The provider missed the window for its residual, not for its fees
+    e.ledger().set_timestamp(start + 1501);
+    assert_eq!(client.withdraw_liquidity(&provider, &market_id), 1);
+    assert_eq!(client.withdraw_liquidity(&provider, &market_id), 0);
+
+    assert_eq!(client.sweep_unclaimed(&admin, &market_id, &treasury), 150);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&contract_id), client.get_collected_fees(&token));
+}
+
+#[test]
+fn test_switching_sides_forfeits_early_bonus() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(PredictionMarket, ());
+    let client = PredictionMarketClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    client.set_platform_fee_rate(&admin, &0);
+
+    let creator = Address::generate(&e);
+    let switcher = Address::generate(&e);
+    let late = Address::generate(&e);
+    let loser = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let token_client = StellarAssetClient::new(&e, &token);
+    token_client.mint(&switcher, &2000);
+    token_client.mint(&late, &2000);
+    token_client.mint(&loser, &2000);
+
+    let market_id = client.create_market(
+        &creator,
+        &MarketParams {
+            question: String::from_str(&e, "Will BTC hit $100k?"),
+            description: String::from_str(&e, "BTC prediction"),
+            token: token.clone(),
+            schedule: schedule(2000),
+            metadata: default_metadata(&e),
+            early_bonus: 10000,
+            commit_reveal: false,
+        },
+    );
+
+    client.predict(&switcher, &market_id, &PredictionSide::Yes, &1000, &None, &None);
+    client.predict(&loser, &market_id, &PredictionSide::Yes, &1000, &None, &None);
+    assert_eq!(client.get_user_prediction(&switcher, &market_id).unwrap().weighted, 2000);
+
+    // Switching one second before close weighs the moved stake as a late bet
+    e.ledger().set_timestamp(1999);
+    client.predict(&switcher, &market_id, &PredictionSide::No, &1, &None, &None);
+    client.predict(&late, &market_id, &PredictionSide::No, &1000, &None, &None);
+
+    assert_eq!(client.get_user_prediction(&switcher, &market_id).unwrap().weighted, 1002);
+    assert_eq!(client.get_user_prediction(&late, &market_id).unwrap().weighted, 1001);
+
+    let market: Market = client.get_market(&market_id);
+    assert_eq!(market.total_yes, 1000);
+    assert_eq!(market.weighted_yes, 2000);
+    assert_eq!(market.weighted_no, 2003);
+
+    e.ledger().set_timestamp(2000);
+    client.resolve_market(&admin, &market_id, &PredictionSide::No, &evidence(&e));
+
+    // Pot of 3001 split 1002:1001, the switcher's early Yes bet earns nothing
+    assert_eq!(client.claim_winnings(&switcher, &market_id), 1501);
+    assert_eq!(client.claim_winnings(&late, &market_id), 1499);
+}
@@ -0,0 +1,235 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractevent, contractimpl, contractmeta, contracttype, token, Address, Env, Vec,
+};
+
+mod test;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Recipients,                    // Vec<Recipient>, shares sum to 10000
+    Accounted(Address),            // Token -> Balance already split between recipients
+    Claimable(Address, Address),   // Recipient -> Token -> Withdrawable amount
+}
+
+/// A payee of the treasury and its share of every deposit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Recipient {
+    pub address: Address,
+    pub share: i128, // Basis points
+}
+
+/// Upper bound on split recipients, keeps `distribute` cheap.
+const MAX_RECIPIENTS: u32 = 10;
+
+/// Emitted when newly received tokens are split between the recipients.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesDistributed {
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a recipient pulls its share out of the treasury.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Withdrawn {
+    #[topic]
+    pub recipient: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+}
+
+fn has_admin(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Admin)
+}
+
+fn get_admin(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+fn set_admin(e: &Env, admin: &Address) {
+    e.storage().instance().set(&DataKey::Admin, admin);
+}
+
+fn get_recipients(e: &Env) -> Vec<Recipient> {
+    e.storage()
+        .instance()
+        .get(&DataKey::Recipients)
+        .unwrap_or(Vec::new(e))
+}
+
+fn set_recipients(e: &Env, recipients: &Vec<Recipient>) {
+    if recipients.is_empty() || recipients.len() > MAX_RECIPIENTS {
+        panic!("recipients must be between 1 and 10");
+    }
+
+    let mut total = 0;
+    for recipient in recipients.iter() {
+        if recipient.share <= 0 {
+            panic!("share must be positive");
+        }
+        total += recipient.share;
+    }
+    if total != 10000 {
+        panic!("shares must sum to 10000");
+    }
+
+    e.storage().instance().set(&DataKey::Recipients, recipients);
+}
+
+fn get_accounted(e: &Env, token: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Accounted(token.clone()))
+        .unwrap_or(0)
+}
+
+fn set_accounted(e: &Env, token: &Address, amount: i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Accounted(token.clone()), &amount);
+}
+
+fn get_claimable(e: &Env, recipient: &Address, token: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Claimable(recipient.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+fn set_claimable(e: &Env, recipient: &Address, token: &Address, amount: i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Claimable(recipient.clone(), token.clone()), &amount);
+}
+
+// Contract metadata
+contractmeta!(
+    key = "Description",
+    val = "Prediction market fee treasury - Splits platform fees between recipients"
+);
+
+#[contract]
+pub struct Treasury;
+
+#[contractimpl]
+impl Treasury {
+    /// Initialize the treasury with an admin and the initial split
+    pub fn initialize(e: Env, admin: Address, recipients: Vec<Recipient>) {
+        if has_admin(&e) {
+            panic!("already initialized");
+        }
+
+        set_admin(&e, &admin);
+        set_recipients(&e, &recipients);
+    }
+
+    /// Split every token received since the last call between the
+    /// recipients. Callable by anyone, depositors transfer first and then
+    /// call this. The rounding remainder goes to the last recipient.
+    pub fn distribute(e: Env, token: Address) -> i128 {
+        let token_client = token::Client::new(&e, &token);
+        let balance = token_client.balance(&e.current_contract_address());
+        let amount = balance - get_accounted(&e, &token);
+
+        if amount <= 0 {
+            return 0;
+        }
+
+        let recipients = get_recipients(&e);
+        let mut allocated = 0;
+        for (i, recipient) in recipients.iter().enumerate() {
+            let share = if i as u32 == recipients.len() - 1 {
+                amount - allocated
+            } else {
+                (amount * recipient.share) / 10000
+            };
+            allocated += share;
+
+            let claimable = get_claimable(&e, &recipient.address, &token) + share;
+            set_claimable(&e, &recipient.address, &token, claimable);
+        }
+
+        set_accounted(&e, &token, balance);
+
+        FeesDistributed { token, amount }.publish(&e);
+
+        amount
+    }
+
+    /// Withdraw a recipient's share of one token
+    pub fn withdraw(e: Env, recipient: Address, token: Address) -> i128 {
+        recipient.require_auth();
+
+        let amount = get_claimable(&e, &recipient, &token);
+        if amount <= 0 {
+            panic!("nothing to withdraw");
+        }
+
+        set_claimable(&e, &recipient, &token, 0);
+        set_accounted(&e, &token, get_accounted(&e, &token) - amount);
+
+        let token_client = token::Client::new(&e, &token);
+        token_client.transfer(&e.current_contract_address(), &recipient, &amount);
+
+        Withdrawn {
+            recipient,
+            token,
+            amount,
+        }
+        .publish(&e);
+
+        amount
+    }
+
+    /// Get the amount of a token held for recipients
+    pub fn get_balance(e: Env, token: Address) -> i128 {
+        get_accounted(&e, &token)
+    }
+
+    /// Get what a recipient can withdraw in one token
+    pub fn get_claimable(e: Env, recipient: Address, token: Address) -> i128 {
+        get_claimable(&e, &recipient, &token)
+    }
+
+    /// Get the current split
+    pub fn get_recipients(e: Env) -> Vec<Recipient> {
+        get_recipients(&e)
+    }
+
+    /// Replace the split applied to future deposits (admin only)
+    pub fn set_recipients(e: Env, admin: Address, recipients: Vec<Recipient>) {
+        admin.require_auth();
+
+        let treasury_admin = get_admin(&e);
+        if admin != treasury_admin {
+            panic!("not authorized");
+        }
+
+        set_recipients(&e, &recipients);
+    }
+
+    /// Update admin (admin only)
+    pub fn update_admin(e: Env, admin: Address, new_admin: Address) {
+        admin.require_auth();
+
+        let treasury_admin = get_admin(&e);
+        if admin != treasury_admin {
+            panic!("not authorized");
+        }
+
+        set_admin(&e, &new_admin);
+    }
+
+    /// Get admin address
+    pub fn get_admin(e: Env) -> Address {
+        get_admin(&e)
+    }
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events}, token::StellarAssetClient, vec, Address, Env, Event,
+};
+
+fn create_token(e: &Env, admin: &Address) -> Address {
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    sac.address()
+}
+
+#[test]
+fn test_initialize() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let ops = Address::generate(&e);
+    let contract_id = e.register(Treasury, ());
+    let client = TreasuryClient::new(&e, &contract_id);
+
+    let recipients = vec![&e, Recipient { address: ops.clone(), share: 10000 }];
+    client.initialize(&admin, &recipients);
+
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_recipients(), recipients);
+}
+
+#[test]
+fn test_distribute_and_withdraw() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let ops = Address::generate(&e);
+    let insurance = Address::generate(&e);
+    let contract_id = e.register(Treasury, ());
+    let client = TreasuryClient::new(&e, &contract_id);
+
+    client.initialize(
+        &admin,
+        &vec![
+            &e,
+            Recipient { address: ops.clone(), share: 7000 },
+            Recipient { address: insurance.clone(), share: 3000 },
+        ],
+    );
+
+    let token = create_token(&e, &admin);
+    let other_token = create_token(&e, &admin);
+    StellarAssetClient::new(&e, &token).mint(&contract_id, &1001);
+    StellarAssetClient::new(&e, &other_token).mint(&contract_id, &50);
+
+    // 70/30, the rounding remainder goes to the last recipient
+    assert_eq!(client.distribute(&token), 1001);
+    let event = FeesDistributed {
+        token: token.clone(),
+        amount: 1001,
+    };
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![&e, (contract_id.clone(), event.topics(&e), event.data(&e))]
+    );
+
+    assert_eq!(client.get_claimable(&ops, &token), 700);
+    assert_eq!(client.get_claimable(&insurance, &token), 301);
+    assert_eq!(client.get_balance(&token), 1001);
+
+    // Nothing new to split
+    assert_eq!(client.distribute(&token), 0);
+
+    // Balances are kept per token
+    assert_eq!(client.distribute(&other_token), 50);
+    assert_eq!(client.get_claimable(&ops, &other_token), 35);
+    assert_eq!(client.get_claimable(&insurance, &other_token), 15);
+
+    assert_eq!(client.withdraw(&ops, &token), 700);
+    assert_eq!(client.get_claimable(&ops, &token), 0);
+    assert_eq!(client.get_balance(&token), 301);
+
+    let token_view = soroban_sdk::token::Client::new(&e, &token);
+    assert_eq!(token_view.balance(&ops), 700);
+    assert_eq!(token_view.balance(&contract_id), 301);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.withdraw(&ops, &token);
+    }));
+    assert!(result.is_err());
+
+    // A new split only applies to later deposits
+    client.set_recipients(&admin, &vec![&e, Recipient { address: ops.clone(), share: 10000 }]);
+    StellarAssetClient::new(&e, &token).mint(&contract_id, &100);
+    assert_eq!(client.distribute(&token), 100);
+    assert_eq!(client.get_claimable(&ops, &token), 100);
+    assert_eq!(client.get_claimable(&insurance, &token), 301);
+}
+
+#[test]
+#[should_panic(expected = "shares must sum to 10000")]
+fn test_recipient_shares_must_sum_to_whole() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let ops = Address::generate(&e);
+    let contract_id = e.register(Treasury, ());
+    let client = TreasuryClient::new(&e, &contract_id);
+
+    client.initialize(&admin, &vec![&e, Recipient { address: ops, share: 9000 }]);
+}
+
+#[test]
+#[should_panic(expected = "not authorized")]
+fn test_set_recipients_unauthorized() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let ops = Address::generate(&e);
+    let contract_id = e.register(Treasury, ());
+    let client = TreasuryClient::new(&e, &contract_id);
+
+    client.initialize(&admin, &vec![&e, Recipient { address: ops.clone(), share: 10000 }]);
+
+    let not_admin = Address::generate(&e);
+    client.set_recipients(&not_admin, &vec![&e, Recipient { address: ops, share: 10000 }]);
+}
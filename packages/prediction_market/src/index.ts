@@ -73,302 +73,1021 @@ export function createClient(network: keyof typeof NETWORKS, options?: Omit<Cont
   });
 }
 
-export type DataKey = {tag: "Admin", values: void} | {tag: "Markets", values: readonly [u64]} | {tag: "MarketCount", values: void} | {tag: "UserPredictions", values: readonly [string, u64]} | {tag: "PlatformFeeRate", values: void} | {tag: "WinningSide", values: readonly [u64]};
+export interface Market {
+  cancelled: boolean;
+  close_time: u64;
+  commit_reveal: boolean;
+  condition: MarketCondition;
+  created_at: u64;
+  creator: string;
+  delisted: boolean;
+  description: string;
+  early_bonus: i128;
+  fee_basis: FeeBasis;
+  fee_rate: i128;
+  lp_no: i128;
+  lp_yes: i128;
+  metadata: MarketMetadata;
+  question: string;
+  resolve_after: u64;
+  resolve_deadline: u64;
+  resolved: boolean;
+  token: string;
+  total_no: i128;
+  total_yes: i128;
+  weighted_no: i128;
+  weighted_yes: i128;
+}
 
-export type PredictionSide = {tag: "Yes", values: void} | {tag: "No", values: void};
+export type DataKey = {tag: "Admin", values: void} | {tag: "Markets", values: readonly [u64]} | {tag: "MarketCount", values: void} | {tag: "UserPredictions", values: readonly [string, u64]} | {tag: "PlatformFeeRate", values: void} | {tag: "WinningSide", values: readonly [u64]} | {tag: "Settlement", values: readonly [u64]} | {tag: "CollectedFees", values: readonly [string]} | {tag: "ClaimWindow", values: void} | {tag: "KeeperTipRate", values: void} | {tag: "Series", values: readonly [u64]} | {tag: "SeriesCount", values: void} | {tag: "SeriesMarket", values: readonly [u64, u32]} | {tag: "LpPositions", values: readonly [string, u64]} | {tag: "LpFeeShare", values: void} | {tag: "ResolutionWindow", values: void} | {tag: "Commitments", values: readonly [string, u64]} | {tag: "CommitPenaltyRate", values: void} | {tag: "Referrals", values: readonly [string, u64]} | {tag: "ReferrerStats", values: readonly [string, string]} | {tag: "ReferralShare", values: void} | {tag: "FeeTiers", values: readonly [FeeTierKind]} | {tag: "UserVolume", values: readonly [string]} | {tag: "StakingToken", values: void} | {tag: "StakedBalance", values: readonly [string]} | {tag: "FeeBasis", values: void} | {tag: "Treasury", values: void} | {tag: "InsuranceShare", values: void} | {tag: "InsuranceCap", values: void} | {tag: "InsuranceFund", values: readonly [string]} | {tag: "Resolver", values: void} | {tag: "Signers", values: void} | {tag: "Threshold", values: void} | {tag: "SignersVersion", values: void} | {tag: "Proposals", values: readonly [u64]} | {tag: "ProposalCount", values: void} | {tag: "PendingAdmin", values: void} | {tag: "PendingFeeRate", values: void} | {tag: "CreationPolicy", values: void} | {tag: "Allowlisted", values: readonly [string]} | {tag: "CreatorReputation", values: readonly [string]} | {tag: "MinCreatorReputation", values: void} | {tag: "Moderators", values: readonly [string]} | {tag: "Flags", values: readonly [u64, string]} | {tag: "FlagCount", values: readonly [u64]} | {tag: "Delistings", values: readonly [u64]} | {tag: "Resolutions", values: readonly [u64]} | {tag: "CommitmentCount", values: readonly [u64]} | {tag: "StakeUnlocksAt", values: readonly [string]} | {tag: "Contested", values: readonly [u64]};
+
+
+/**
+ * A discounted fee rate for users at or above `threshold`.
+ */
+export interface FeeTier {
+  fee_rate: i128;
+  threshold: i128;
+}
+
+/**
+ * What the platform fee is charged on.
+ */
+export type FeeBasis = {tag: "Gross", values: void} | {tag: "Profit", values: void};
+
+
+/**
+ * An admin action waiting for signer approvals.
+ */
+export interface Proposal {
+  action: AdminAction;
+  approvals: Array<string>;
+  executed: boolean;
+  expires_at: u64;
+  id: u64;
+  proposer: string;
+  signers_version: u32;
+}
+
+
+/**
+ * A moderation decision hiding a market.
+ */
+export interface Delisting {
+  delisted_at: u64;
+  moderator: string;
+  reason: string;
+}
+
+
+/**
+ * A hidden prediction in a commit-reveal market. `deposit` is escrowed and
+ * must cover the revealed amount; the excess is returned at reveal.
+ */
+export interface Commitment {
+  committed_at: u64;
+  deposit: i128;
+  hash: Buffer;
+  penalty_rate: i128;
+}
+
+/**
+ * Storage keys added after `DataKey` reached the 50 variants a contract
+ * type enum can hold.
+ */
+export type DataKeyExt = {tag: "PendingFeeBasis", values: void} | {tag: "ResolvedChildren", values: readonly [u64]} | {tag: "Referred", values: readonly [string, u64]};
+
+
+/**
+ * Balanced seed liquidity a provider put into a market. The position's
+ * shares are `yes + no`.
+ */
+export interface LpPosition {
+  fees_withdrawn: i128;
+  no: i128;
+  principal_withdrawn: boolean;
+  yes: i128;
+}
+
+
+/**
+ * A user's report of a market, e.g. a misleading or abusive question.
+ */
+export interface MarketFlag {
+  flagged_at: u64;
+  reason: string;
+}
+
+
+/**
+ * Implied probability of each side, from the share of the pool it holds.
+ */
+export interface MarketOdds {
+  no: i128;
+  yes: i128;
+}
 
 
 export interface Prediction {
   amount: i128;
   side: PredictionSide;
+  weighted: i128;
 }
 
 
-export interface Market {
-  created_at: u64;
+/**
+ * How and why a market was resolved.
+ */
+export interface Resolution {
+  evidence: ResolutionEvidence;
+  resolved_at: u64;
+  resolver: string;
+  winning_side: PredictionSide;
+}
+
+/**
+ * A sensitive admin action that needs multisig approval.
+ */
+export type AdminAction = {tag: "UpdateAdmin", values: readonly [string]} | {tag: "SetPlatformFeeRate", values: readonly [i128]} | {tag: "ResolveMarket", values: readonly [u64, PredictionSide, ResolutionEvidence]} | {tag: "SetSigners", values: readonly [Array<string>, u32]} | {tag: "SetResolver", values: readonly [string]} | {tag: "SetTreasury", values: readonly [string]} | {tag: "SweepUnclaimed", values: readonly [u64, string]} | {tag: "InsurancePayout", values: readonly [u64, Array<Compensation>]} | {tag: "SetFeeBasis", values: readonly [FeeBasis]} | {tag: "SetCommitPenaltyRate", values: readonly [i128]} | {tag: "SetClaimWindow", values: readonly [u64]} | {tag: "SetResolutionWindow", values: readonly [u64]} | {tag: "SetModerator", values: readonly [string, boolean]} | {tag: "SetKeeperTipRate", values: readonly [i128]} | {tag: "SetLpFeeShare", values: readonly [i128]} | {tag: "SetReferralShare", values: readonly [i128]} | {tag: "SetInsuranceShare", values: readonly [i128]} | {tag: "SetInsuranceCap", values: readonly [i128]} | {tag: "SetFeeTiers", values: readonly [FeeTierKind, Array<FeeTier>]} | {tag: "SetCreationPolicy", values: readonly [CreationPolicy]} | {tag: "SetAllowlisted", values: readonly [string, boolean]} | {tag: "SetMinCreatorReputation", values: readonly [u32]};
+
+
+export interface ClaimResult {
+  amount: i128;
+  market_id: u64;
+  status: ClaimStatus;
+}
+
+/**
+ * Outcome of a single market in a batch claim.
+ */
+export type ClaimStatus = {tag: "Paid", values: void} | {tag: "Refunded", values: void} | {tag: "NoPrediction", values: void} | {tag: "NotResolved", values: void} | {tag: "NotWinner", values: void} | {tag: "ClaimWindowClosed", values: void} | {tag: "Contested", values: void};
+
+/**
+ * What a fee tier table is keyed on.
+ */
+export type FeeTierKind = {tag: "Volume", values: void} | {tag: "Stake", values: void};
+
+
+/**
+ * Compensation owed to one user of a mis-resolved market.
+ */
+export interface Compensation {
+  amount: i128;
+  recipient: string;
+}
+
+
+/**
+ * What a creator chooses for a new market.
+ */
+export interface MarketParams {
+  commit_reveal: boolean;
+  description: string;
+  early_bonus: i128;
+  metadata: MarketMetadata;
+  question: string;
+  schedule: MarketSchedule;
+  token: string;
+}
+
+
+/**
+ * Template for a question that is asked again on a fixed cadence. Anyone can
+ * roll the series to open the next round once the previous one has ended.
+ * The question is reused verbatim for every round.
+ */
+export interface MarketSeries {
+  cadence: u64;
   creator: string;
+  current_market_id: Option<u64>;
   description: string;
-  end_time: u64;
+  duration: u64;
+  last_rolled_at: u64;
+  metadata: MarketMetadata;
   question: string;
-  resolved: boolean;
+  rounds: u32;
   token: string;
-  total_no: i128;
-  total_yes: i128;
 }
 
+
+/**
+ * What a creator chooses for a recurring market series.
+ */
+export interface SeriesParams {
+  cadence: u64;
+  description: string;
+  duration: u64;
+  metadata: MarketMetadata;
+  question: string;
+  token: string;
+}
+
+/**
+ * Why `create_market` rejected a creator.
+ */
+export const CreationError = {
+  1: {message:"AdminOnly"},
+  2: {message:"NotAllowlisted"},
+  3: {message:"InsufficientReputation"}
+}
+
+
+/**
+ * Attribution and rewards of a referrer in one token.
+ */
+export interface ReferrerStats {
+  claimed: i128;
+  earned: i128;
+  referred: u32;
+  volume: i128;
+}
+
+
+
+
+/**
+ * Who may create markets and series. The admin always can.
+ */
+export type CreationPolicy = {tag: "Open", values: void} | {tag: "Allowlist", values: void} | {tag: "AdminOnly", values: void};
+
+
+/**
+ * Descriptive data shown by frontends, editable by the creator until the
+ * first prediction is placed.
+ */
+export interface MarketMetadata {
+  category: string;
+  image_uri: string;
+  resolution_source: string;
+  tags: Array<string>;
+}
+
+
+/**
+ * When a market stops taking predictions and when it may be resolved.
+ * Betting usually closes before the event, with the outcome known later.
+ */
+export interface MarketSchedule {
+  close_time: u64;
+  resolve_after: u64;
+  resolve_deadline: Option<u64>;
+}
+
+
+/**
+ * A platform fee increase waiting out its timelock.
+ */
+export interface PendingFeeRate {
+  effective_at: u64;
+  rate: i128;
+}
+
+export type PredictionSide = {tag: "Yes", values: void} | {tag: "No", values: void};
+
+
+
+
+
+
+
+
+/**
+ * Ties a conditional market to the outcome of a parent market. If the parent
+ * resolves to a different outcome the conditional market is cancelled and
+ * every stake is refunded.
+ */
+export type MarketCondition = {tag: "None", values: void} | {tag: "Parent", values: readonly [u64, PredictionSide]};
+
+
+/**
+ * A switch to the gross fee basis waiting out the fee timelock.
+ */
+export interface PendingFeeBasis {
+  basis: FeeBasis;
+  effective_at: u64;
+}
+
+
+
+/**
+ * Payout accounting for a resolved market.
+ */
+export interface MarketSettlement {
+  claim_deadline: Option<u64>;
+  claimed_stake: i128;
+  distributed: i128;
+  dust_swept: i128;
+  insurance_paid: i128;
+  lp_fees: i128;
+  resolved_at: u64;
+  unclaimed_swept: i128;
+}
+
+
+/**
+ * Bounds a user puts on a prediction.
+ */
+export interface PredictionLimits {
+  deadline: u32;
+  min_payout: i128;
+}
+
+
+
+/**
+ * What a resolver attaches to an outcome so users can audit it.
+ */
+export interface ResolutionEvidence {
+  justification: string;
+  reference: string;
+}
+
+
+
+
+
 export interface Client {
+  /**
+   * Construct and simulate a stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stake governance tokens towards a fee discount tier
+   */
+  stake: ({user, amount}: {user: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a predict transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Place a prediction on a market. `limits` rejects the bet if
+   * `quote_payout` for it has dropped below `min_payout`, or if the ledger
+   * sequence is past `deadline`.
+   */
+  predict: ({user, market_id, side, amount, referrer, limits}: {user: string, market_id: u64, side: PredictionSide, amount: i128, referrer: Option<string>, limits: Option<PredictionLimits>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a unstake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw staked governance tokens once the lockup has passed
+   */
+  unstake: ({user, amount}: {user: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_flag transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a user's flag on a market
+   */
+  get_flag: ({market_id, user}: {market_id: u64, user: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<MarketFlag>>>
+
+  /**
+   * Construct and simulate a get_odds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the implied probability of each side in basis points. An empty
+   * pool is even.
+   */
+  get_odds: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<MarketOdds>>
+
+  /**
+   * Construct and simulate a claim_for transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Trigger a payout on behalf of a winner. Winnings always go to `user`,
+   * `caller` only receives the keeper tip configured by the admin. Refunds
+   * of cancelled markets are passed on in full, without a tip.
+   */
+  claim_for: ({caller, user, market_id}: {caller: string, user: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get admin address
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a claim_many transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim winnings from several resolved markets with a single auth.
+   * Markets that can't be claimed are skipped and reported in the result,
+   * payouts are aggregated into one transfer per token.
+   */
+  claim_many: ({user, market_ids}: {user: string, market_ids: Array<u64>}, options?: MethodOptions) => Promise<AssembledTransaction<Array<ClaimResult>>>
+
+  /**
+   * Construct and simulate a get_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get market details
+   */
+  get_market: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Market>>
+
+  /**
+   * Construct and simulate a get_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a market series
+   */
+  get_series: ({series_id}: {series_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<MarketSeries>>
+
   /**
    * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Initialize the contract with an admin address
    */
-  initialize: ({admin}: {admin: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  initialize: ({admin}: {admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a sweep_dust transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Move the rounding remainder of a resolved market into platform fees.
+   * Callable by anyone once every winning stake has been claimed. Winnings
+   * left after the claim deadline go through `sweep_unclaimed` instead.
+   */
+  sweep_dust: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a flag_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Flag a market for moderators to review. Each user can flag a market
+   * once; returns the market's flag count.
+   */
+  flag_market: ({user, market_id, reason}: {user: string, market_id: u64, reason: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_signers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the multisig signers, empty if multisig is disabled
+   */
+  get_signers: (options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a roll_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open the next market of a series. Callable by anyone once the previous
+   * round has ended and a full cadence has elapsed since it was opened.
+   */
+  roll_series: ({series_id}: {series_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a set_signers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Enable multisig approval for sensitive admin actions (admin only).
+   * Once enabled, signers change through a `SetSigners` proposal.
+   */
+  set_signers: ({admin, signers, threshold}: {admin: string, signers: Array<string>, threshold: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin transfer (nominated admin only)
+   */
+  accept_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a forward_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Forward a token's collected fees (e.g. swept dust) to the treasury.
+   * Callable by anyone, claims forward their fees automatically.
+   */
+  forward_fees: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a proposal
+   */
+  get_proposal: ({proposal_id}: {proposal_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Proposal>>
+
+  /**
+   * Construct and simulate a get_referrer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the referrer a user's prediction on a market is attributed to
+   */
+  get_referrer: ({user, market_id}: {user: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_resolver transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the contract allowed to resolve markets besides the admin
+   */
+  get_resolver: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the treasury platform fees are forwarded to
+   */
+  get_treasury: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a is_moderator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an address can delist markets
+   */
+  is_moderator: ({moderator}: {moderator: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  /**
+   * Construct and simulate a quote_payout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Quote the payout, after the platform fee, of a new `amount` stake on
+   * `side` if the market resolved that way right after it was placed.
+   * Fee tier discounts aren't applied.
+   */
+  quote_payout: ({market_id, side, amount}: {market_id: u64, side: PredictionSide, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a set_resolver transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let a contract, e.g. the governance token's dispute vote, resolve
+   * markets (admin only, through a proposal when multisig is enabled)
+   */
+  set_resolver: ({admin, resolver}: {admin: string, resolver: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the treasury platform fees are forwarded to (admin only, through
+   * a proposal when multisig is enabled)
+   */
+  set_treasury: ({admin, treasury}: {admin: string, treasury: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a update_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Nominate a new admin, who takes over once they call `accept_admin`
+   * (admin only, through a proposal when multisig is enabled)
+   */
+  update_admin: ({admin, new_admin}: {admin: string, new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
    * Construct and simulate a create_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Create a new prediction market
    */
-  create_market: ({creator, question, description, token, end_time}: {creator: string, question: string, description: string, token: string, end_time: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  create_market: ({creator, params}: {creator: string, params: MarketParams}, options?: MethodOptions) => Promise<AssembledTransaction<u64>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a create_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register a recurring market series. Rounds are opened with `roll_series`.
+   */
+  create_series: ({creator, params}: {creator: string, params: SeriesParams}, options?: MethodOptions) => Promise<AssembledTransaction<u64>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  /**
+   * Construct and simulate a delist_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hide a market from list views (admin or moderator only). An
+   * unresolved market is also cancelled, so every participant can reclaim
+   * their full stake.
+   */
+  delist_market: ({moderator, market_id, reason}: {moderator: string, market_id: u64, reason: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a predict transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Place a prediction on a market
+   * Construct and simulate a expire_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Invalidate a market nobody resolved before its deadline. Callable by
+   * anyone; every participant can then reclaim their full stake.
    */
-  predict: ({user, market_id, side, amount}: {user: string, market_id: u64, side: PredictionSide, amount: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  expire_market: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_claimable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get what `claim_winnings` would pay a user right now, 0 if nothing
+   */
+  get_claimable: ({user, market_id}: {user: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  /**
+   * Construct and simulate a get_delisting transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the moderation decision that delisted a market
+   */
+  get_delisting: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Delisting>>>
 
   /**
-   * Construct and simulate a resolve_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Resolve a market (admin only) and distribute winnings
+   * Construct and simulate a get_fee_basis transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get what the platform fee is charged on
+   */
+  get_fee_basis: (options?: MethodOptions) => Promise<AssembledTransaction<FeeBasis>>
+
+  /**
+   * Construct and simulate a get_fee_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a fee tier table
+   */
+  get_fee_tiers: ({kind}: {kind: FeeTierKind}, options?: MethodOptions) => Promise<AssembledTransaction<Array<FeeTier>>>
+
+  /**
+   * Construct and simulate a get_threshold transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of approvals a proposal needs
    */
-  resolve_market: ({admin, market_id, winning_side}: {admin: string, market_id: u64, winning_side: PredictionSide}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_threshold: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a set_fee_basis transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set what the platform fee is charged on (admin only, through a
+   * proposal when multisig is enabled). Switching to gross is timelocked.
+   */
+  set_fee_basis: ({admin, basis}: {admin: string, basis: FeeBasis}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  /**
+   * Construct and simulate a set_fee_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace a fee tier table (admin only)
+   */
+  set_fee_tiers: ({admin, kind, tiers}: {admin: string, kind: FeeTierKind, tiers: Array<FeeTier>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_moderator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant or revoke the moderator role (admin only)
+   */
+  set_moderator: ({admin, moderator, enabled}: {admin: string, moderator: string, enabled: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a approve_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve a pending proposal (signers only). The action executes as
+   * soon as the threshold is met.
+   */
+  approve_action: ({signer, proposal_id}: {signer: string, proposal_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
    * Construct and simulate a claim_winnings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Claim winnings from a resolved market
    */
-  claim_winnings: ({user, market_id}: {user: string, market_id: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  claim_winnings: ({user, market_id}: {user: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_commitment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a user's unrevealed commitment on a commit-reveal market
+   */
+  get_commitment: ({user, market_id}: {user: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Commitment>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  /**
+   * Construct and simulate a get_flag_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of users who flagged a market
+   */
+  get_flag_count: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a get_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get market details
+   * Construct and simulate a get_resolution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the outcome of a market with its resolver, evidence and time
    */
-  get_market: ({market_id}: {market_id: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_resolution: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Resolution>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a is_allowlisted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a creator is on the allowlist
+   */
+  is_allowlisted: ({creator}: {creator: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Market>>
+  /**
+   * Construct and simulate a propose_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a sensitive admin action (signers only). The proposal counts
+   * as the proposer's approval.
+   */
+  propose_action: ({signer, action}: {signer: string, action: AdminAction}, options?: MethodOptions) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a get_user_prediction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get user's prediction for a specific market
+   * Construct and simulate a resolve_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve a market (admin or resolver only) and distribute winnings.
+   * The evidence is stored with the outcome, see `get_resolution`.
+   */
+  resolve_market: ({admin, market_id, winning_side, evidence}: {admin: string, market_id: u64, winning_side: PredictionSide, evidence: ResolutionEvidence}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_lp_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a provider's liquidity position in a market
+   */
+  get_lp_position: ({provider, market_id}: {provider: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<LpPosition>>>
+
+  /**
+   * Construct and simulate a get_user_volume transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a user's cumulative prediction volume
    */
-  get_user_prediction: ({user, market_id}: {user: string, market_id: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_user_volume: ({user}: {user: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a set_allowlisted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add a creator to or remove it from the allowlist (admin only)
+   */
+  set_allowlisted: ({admin, creator, allowed}: {admin: string, creator: string, allowed: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<Prediction>>>
+  /**
+   * Construct and simulate a sweep_unclaimed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sweep collateral left unclaimed after the claim deadline (admin only,
+   * through a proposal when multisig is enabled)
+   */
+  sweep_unclaimed: ({admin, market_id, to}: {admin: string, market_id: u64, to: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_claim_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the claim window (in seconds) applied to newly resolved markets
+   */
+  get_claim_window: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_lp_fee_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the share of platform fees paid to liquidity providers (in basis points)
+   */
+  get_lp_fee_share: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
   /**
    * Construct and simulate a get_market_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get total market count
    */
-  get_market_count: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_market_count: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_series_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get total series count
+   */
+  get_series_count: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_winning_side transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get winning side for a market
+   */
+  get_winning_side: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<PredictionSide>>>
+
+  /**
+   * Construct and simulate a insurance_payout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Compensate users of a market later found to be mis-resolved, out of
+   * the insurance fund of the market's token (admin only, through a
+   * proposal when multisig is enabled). Payouts on one market are capped
+   * at the insurance cap share of its pool.
+   */
+  insurance_payout: ({admin, market_id, recipients}: {admin: string, market_id: u64, recipients: Array<Compensation>}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a set_claim_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the claim window (admin only), 0 disables the deadline
+   */
+  set_claim_window: ({admin, window}: {admin: string, window: u64}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a set_lp_fee_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the share of platform fees paid to liquidity providers (admin only)
+   */
+  set_lp_fee_share: ({admin, share}: {admin: string, share: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a commit_prediction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit to a hidden prediction on a commit-reveal market, escrowing
+   * `deposit`. The prediction only counts once revealed after close_time.
+   */
+  commit_prediction: ({user, market_id, hash, deposit}: {user: string, market_id: u64, hash: Buffer, deposit: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  /**
+   * Construct and simulate a get_insurance_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the per-incident insurance cap (in basis points of a market's pool)
+   */
+  get_insurance_cap: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the nominated admin that hasn't accepted yet
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_staking_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the governance token accepted by `stake`
+   */
+  get_staking_token: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a provide_liquidity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seed both sides of a market with balanced liquidity. The provider
+   * receives one LP share per token deposited.
+   */
+  provide_liquidity: ({provider, market_id, amount}: {provider: string, market_id: u64, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a refund_commitment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Return a commitment that was never revealed, once the reveal window
+   * has closed. The penalty rate in force at commit time is kept as
+   * platform fees unless the market was cancelled.
+   */
+  refund_commitment: ({user, market_id}: {user: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a reveal_prediction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a committed prediction between close_time and resolve_after.
+   * The stake joins the pool and the rest of the deposit is returned.
+   */
+  reveal_prediction: ({user, market_id, side, amount, salt}: {user: string, market_id: u64, side: PredictionSide, amount: i128, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_insurance_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the per-incident insurance cap (admin only)
+   */
+  set_insurance_cap: ({admin, cap}: {admin: string, cap: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_staking_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the governance token accepted by `stake` (admin only, once)
+   */
+  set_staking_token: ({admin, token}: {admin: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a compute_commitment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Compute the commitment hash for a user's hidden prediction on a market
+   */
+  compute_commitment: ({user, market_id, side, amount, salt}: {user: string, market_id: u64, side: PredictionSide, amount: i128, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a contest_resolution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Put a resolved market's outcome to a new vote (resolver only). Payouts
+   * are frozen until the resolver calls `resolve_market` again, which
+   * replaces or upholds the outcome. Only possible once per market, before
+   * anything has been paid out and before a conditional market depending
+   * on the outcome has resolved.
+   */
+  contest_resolution: ({resolver, market_id}: {resolver: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_collected_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get platform fees (including swept dust) held for a token
+   */
+  get_collected_fees: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_insurance_fund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the insurance fund held for a token
+   */
+  get_insurance_fund: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_referral_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the share of platform fees paid to referrers (in basis points)
+   */
+  get_referral_share: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_referrer_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a referrer's volume and rewards in one token
+   */
+  get_referrer_stats: ({referrer, token}: {referrer: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<ReferrerStats>>
+
+  /**
+   * Construct and simulate a get_series_markets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get market ids of a series, oldest first, for rounds `start..start + limit`
+   */
+  get_series_markets: ({series_id, start, limit}: {series_id: u64, start: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_staked_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a user's staked governance token balance
+   */
+  get_staked_balance: ({user}: {user: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a set_referral_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the share of platform fees paid to referrers (admin only)
+   */
+  set_referral_share: ({admin, share}: {admin: string, share: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a withdraw_liquidity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a provider's pro-rata residual and fee cut after resolution,
+   * or the full deposit if the market was cancelled. Can be called again
+   * later to collect fees accrued since the last withdrawal. Past the claim
+   * deadline only fees are paid, the residual is left to `sweep_unclaimed`.
+   */
+  withdraw_liquidity: ({provider, market_id}: {provider: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_creation_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get who may create markets
+   */
+  get_creation_policy: (options?: MethodOptions) => Promise<AssembledTransaction<CreationPolicy>>
+
+  /**
+   * Construct and simulate a get_insurance_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the share of platform fees set aside for insurance (in basis points)
+   */
+  get_insurance_share: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_keeper_tip_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get keeper tip rate (in basis points of winnings)
+   */
+  get_keeper_tip_rate: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_user_prediction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get user's prediction for a specific market
+   */
+  get_user_prediction: ({user, market_id}: {user: string, market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Prediction>>>
+
+  /**
+   * Construct and simulate a is_market_cancelled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a market has been cancelled (stakes are refundable)
+   */
+  is_market_cancelled: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_creation_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set who may create markets (admin only)
+   */
+  set_creation_policy: ({admin, policy}: {admin: string, policy: CreationPolicy}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_insurance_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the share of platform fees set aside for insurance (admin only)
+   */
+  set_insurance_share: ({admin, share}: {admin: string, share: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_keeper_tip_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set keeper tip rate (admin only)
+   */
+  set_keeper_tip_rate: ({admin, tip_rate}: {admin: string, tip_rate: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_pending_fee_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a fee increase that hasn't applied yet
+   */
+  get_pending_fee_rate: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingFeeRate>>>
+
+  /**
+   * Construct and simulate a get_market_settlement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get payout accounting for a market
+   */
+  get_market_settlement: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<MarketSettlement>>
+
+  /**
+   * Construct and simulate a get_pending_fee_basis transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a switch to the gross fee basis that hasn't applied yet
+   */
+  get_pending_fee_basis: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingFeeBasis>>>
+
+  /**
+   * Construct and simulate a get_pending_proposals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get proposals that can still be approved, among ids `start..start + limit`
+   */
+  get_pending_proposals: ({start, limit}: {start: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<Proposal>>>
 
   /**
    * Construct and simulate a get_platform_fee_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get platform fee rate
    */
-  get_platform_fee_rate: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  get_platform_fee_rate: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  /**
+   * Construct and simulate a get_resolution_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the default resolution window (in seconds)
+   */
+  get_resolution_window: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>
 
   /**
    * Construct and simulate a set_platform_fee_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set platform fee rate (admin only)
+   * Set platform fee rate (admin only, through a proposal when multisig is enabled).
+   * Increases are timelocked.
    */
-  set_platform_fee_rate: ({admin, fee_rate}: {admin: string, fee_rate: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_platform_fee_rate: ({admin, fee_rate}: {admin: string, fee_rate: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a set_resolution_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the default resolution window applied to markets created without
+   * an explicit deadline (admin only)
+   */
+  set_resolution_window: ({admin, window}: {admin: string, window: u64}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  /**
+   * Construct and simulate a claim_referral_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a referrer's accrued rewards in one token
+   */
+  claim_referral_rewards: ({referrer, token}: {referrer: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
   /**
-   * Construct and simulate a update_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update admin (admin only)
+   * Construct and simulate a get_creator_reputation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of a creator's markets that were resolved
    */
-  update_admin: ({admin, new_admin}: {admin: string, new_admin: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_creator_reputation: ({creator}: {creator: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_effective_fee_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the fee rate charged to a user on winnings (in basis points)
+   */
+  get_effective_fee_rate: ({user}: {user: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  /**
+   * Construct and simulate a update_market_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update market metadata (creator only, before the first prediction)
+   */
+  update_market_metadata: ({creator, market_id, metadata}: {creator: string, market_id: u64, metadata: MarketMetadata}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get admin address
+   * Construct and simulate a get_commit_penalty_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the penalty on unrevealed commitments (in basis points)
    */
-  get_admin: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_commit_penalty_rate: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_markets_by_category transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get ids of markets in a category. Scans at most `limit` market ids
+   * starting from `start`, so callers page through with increasing `start`.
+   */
+  get_markets_by_category: ({category, start, limit}: {category: string, start: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  /**
+   * Construct and simulate a is_resolution_contested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a market's resolution is under a dispute vote
+   */
+  is_resolution_contested: ({market_id}: {market_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a get_winning_side transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get winning side for a market
+   * Construct and simulate a set_commit_penalty_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the penalty on unrevealed commitments (admin only)
    */
-  get_winning_side: ({market_id}: {market_id: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_commit_penalty_rate: ({admin, rate}: {admin: string, rate: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a create_conditional_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a market that only settles if a parent market resolves to the
+   * required outcome, and is cancelled with full refunds otherwise
+   */
+  create_conditional_market: ({creator, params, parent_market_id, parent_outcome}: {creator: string, params: MarketParams, parent_market_id: u64, parent_outcome: PredictionSide}, options?: MethodOptions) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_series_current_market transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the market opened by the latest roll of a series
+   */
+  get_series_current_market: ({series_id}: {series_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<u64>>>
+
+  /**
+   * Construct and simulate a get_min_creator_reputation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the reputation creators need under the open policy
+   */
+  get_min_creator_reputation: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<PredictionSide>>>
+  /**
+   * Construct and simulate a set_min_creator_reputation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the reputation creators need under the open policy (admin only).
+   * 0 disables the threshold.
+   */
+  set_min_creator_reputation: ({admin, min_reputation}: {admin: string, min_reputation: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -388,39 +1107,270 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABgAAAAAAAAAAAAAABUFkbWluAAAAAAAAAQAAAAAAAAAHTWFya2V0cwAAAAABAAAABgAAAAAAAAAAAAAAC01hcmtldENvdW50AAAAAAEAAAAAAAAAD1VzZXJQcmVkaWN0aW9ucwAAAAACAAAAEwAAAAYAAAAAAAAAAAAAAA9QbGF0Zm9ybUZlZVJhdGUAAAAAAQAAAAAAAAALV2lubmluZ1NpZGUAAAAAAQAAAAY=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABk1hcmtldAAAAAAAFwAAAAAAAAAJY2FuY2VsbGVkAAAAAAAAAQAAAAAAAAAKY2xvc2VfdGltZQAAAAAABgAAAAAAAAANY29tbWl0X3JldmVhbAAAAAAAAAEAAAAAAAAACWNvbmRpdGlvbgAAAAAAB9AAAAAPTWFya2V0Q29uZGl0aW9uAAAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAhkZWxpc3RlZAAAAAEAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAC2Vhcmx5X2JvbnVzAAAAAAsAAAAAAAAACWZlZV9iYXNpcwAAAAAAB9AAAAAIRmVlQmFzaXMAAAAAAAAACGZlZV9yYXRlAAAACwAAAAAAAAAFbHBfbm8AAAAAAAALAAAAAAAAAAZscF95ZXMAAAAAAAsAAAAAAAAACG1ldGFkYXRhAAAH0AAAAA5NYXJrZXRNZXRhZGF0YQAAAAAAAAAAAAhxdWVzdGlvbgAAABAAAAAAAAAADXJlc29sdmVfYWZ0ZXIAAAAAAAAGAAAAAAAAABByZXNvbHZlX2RlYWRsaW5lAAAABgAAAAAAAAAIcmVzb2x2ZWQAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAACHRvdGFsX25vAAAACwAAAAAAAAAJdG90YWxfeWVzAAAAAAAACwAAAAAAAAALd2VpZ2h0ZWRfbm8AAAAACwAAAAAAAAAMd2VpZ2h0ZWRfeWVzAAAACw==",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAMgAAAAAAAAAAAAAABUFkbWluAAAAAAAAAQAAAAAAAAAHTWFya2V0cwAAAAABAAAABgAAAAAAAAAAAAAAC01hcmtldENvdW50AAAAAAEAAAAAAAAAD1VzZXJQcmVkaWN0aW9ucwAAAAACAAAAEwAAAAYAAAAAAAAAAAAAAA9QbGF0Zm9ybUZlZVJhdGUAAAAAAQAAAAAAAAALV2lubmluZ1NpZGUAAAAAAQAAAAYAAAABAAAAAAAAAApTZXR0bGVtZW50AAAAAAABAAAABgAAAAEAAAAAAAAADUNvbGxlY3RlZEZlZXMAAAAAAAABAAAAEwAAAAAAAAAAAAAAC0NsYWltV2luZG93AAAAAAAAAAAAAAAADUtlZXBlclRpcFJhdGUAAAAAAAABAAAAAAAAAAZTZXJpZXMAAAAAAAEAAAAGAAAAAAAAAAAAAAALU2VyaWVzQ291bnQAAAAAAQAAAAAAAAAMU2VyaWVzTWFya2V0AAAAAgAAAAYAAAAEAAAAAQAAAAAAAAALTHBQb3NpdGlvbnMAAAAAAgAAABMAAAAGAAAAAAAAAAAAAAAKTHBGZWVTaGFyZQAAAAAAAAAAAAAAAAAQUmVzb2x1dGlvbldpbmRvdwAAAAEAAAAAAAAAC0NvbW1pdG1lbnRzAAAAAAIAAAATAAAABgAAAAAAAAAAAAAAEUNvbW1pdFBlbmFsdHlSYXRlAAAAAAAAAQAAAAAAAAAJUmVmZXJyYWxzAAAAAAAAAgAAABMAAAAGAAAAAQAAAAAAAAANUmVmZXJyZXJTdGF0cwAAAAAAAAIAAAATAAAAEwAAAAAAAAAAAAAADVJlZmVycmFsU2hhcmUAAAAAAAABAAAAAAAAAAhGZWVUaWVycwAAAAEAAAfQAAAAC0ZlZVRpZXJLaW5kAAAAAAEAAAAAAAAAClVzZXJWb2x1bWUAAAAAAAEAAAATAAAAAAAAAAAAAAAMU3Rha2luZ1Rva2VuAAAAAQAAAAAAAAANU3Rha2VkQmFsYW5jZQAAAAAAAAEAAAATAAAAAAAAAAAAAAAIRmVlQmFzaXMAAAAAAAAAAAAAAAhUcmVhc3VyeQAAAAAAAAAAAAAADkluc3VyYW5jZVNoYXJlAAAAAAAAAAAAAAAAAAxJbnN1cmFuY2VDYXAAAAABAAAAAAAAAA1JbnN1cmFuY2VGdW5kAAAAAAAAAQAAABMAAAAAAAAAAAAAAAhSZXNvbHZlcgAAAAAAAAAAAAAAB1NpZ25lcnMAAAAAAAAAAAAAAAAJVGhyZXNob2xkAAAAAAAAAAAAAAAAAAAOU2lnbmVyc1ZlcnNpb24AAAAAAAEAAAAAAAAACVByb3Bvc2FscwAAAAAAAAEAAAAGAAAAAAAAAAAAAAANUHJvcG9zYWxDb3VudAAAAAAAAAAAAAAAAAAADFBlbmRpbmdBZG1pbgAAAAAAAAAAAAAADlBlbmRpbmdGZWVSYXRlAAAAAAAAAAAAAAAAAA5DcmVhdGlvblBvbGljeQAAAAAAAQAAAAAAAAALQWxsb3dsaXN0ZWQAAAAAAQAAABMAAAABAAAAAAAAABFDcmVhdG9yUmVwdXRhdGlvbgAAAAAAAAEAAAATAAAAAAAAAAAAAAAUTWluQ3JlYXRvclJlcHV0YXRpb24AAAABAAAAAAAAAApNb2RlcmF0b3JzAAAAAAABAAAAEwAAAAEAAAAAAAAABUZsYWdzAAAAAAAAAgAAAAYAAAATAAAAAQAAAAAAAAAJRmxhZ0NvdW50AAAAAAAAAQAAAAYAAAABAAAAAAAAAApEZWxpc3RpbmdzAAAAAAABAAAABgAAAAEAAAAAAAAAC1Jlc29sdXRpb25zAAAAAAEAAAAGAAAAAQAAAAAAAAAPQ29tbWl0bWVudENvdW50AAAAAAEAAAAGAAAAAQAAAAAAAAAOU3Rha2VVbmxvY2tzQXQAAAAAAAEAAAATAAAAAQAAAAAAAAAJQ29udGVzdGVkAAAAAAAAAQAAAAY=",
+        "AAAAAQAAADhBIGRpc2NvdW50ZWQgZmVlIHJhdGUgZm9yIHVzZXJzIGF0IG9yIGFib3ZlIGB0aHJlc2hvbGRgLgAAAAAAAAAHRmVlVGllcgAAAAACAAAAAAAAAAhmZWVfcmF0ZQAAAAsAAAAAAAAACXRocmVzaG9sZAAAAAAAAAs=",
+        "AAAAAgAAACRXaGF0IHRoZSBwbGF0Zm9ybSBmZWUgaXMgY2hhcmdlZCBvbi4AAAAAAAAACEZlZUJhc2lzAAAAAgAAAAAAAAAAAAAABUdyb3NzAAAAAAAAAAAAAAAAAAAGUHJvZml0AAA=",
+        "AAAAAQAAAC1BbiBhZG1pbiBhY3Rpb24gd2FpdGluZyBmb3Igc2lnbmVyIGFwcHJvdmFscy4AAAAAAAAAAAAACFByb3Bvc2FsAAAABwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAAC0FkbWluQWN0aW9uAAAAAAAAAAAJYXBwcm92YWxzAAAAAAAD6gAAABMAAAAAAAAACGV4ZWN1dGVkAAAAAQAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAPc2lnbmVyc192ZXJzaW9uAAAAAAQ=",
+        "AAAAAQAAACZBIG1vZGVyYXRpb24gZGVjaXNpb24gaGlkaW5nIGEgbWFya2V0LgAAAAAAAAAAAAlEZWxpc3RpbmcAAAAAAAADAAAAAAAAAAtkZWxpc3RlZF9hdAAAAAAGAAAAAAAAAAltb2RlcmF0b3IAAAAAAAATAAAAAAAAAAZyZWFzb24AAAAAABA=",
+        "AAAAAQAAAIpBIGhpZGRlbiBwcmVkaWN0aW9uIGluIGEgY29tbWl0LXJldmVhbCBtYXJrZXQuIGBkZXBvc2l0YCBpcyBlc2Nyb3dlZCBhbmQKbXVzdCBjb3ZlciB0aGUgcmV2ZWFsZWQgYW1vdW50OyB0aGUgZXhjZXNzIGlzIHJldHVybmVkIGF0IHJldmVhbC4AAAAAAAAAAAAKQ29tbWl0bWVudAAAAAAABAAAAAAAAAAMY29tbWl0dGVkX2F0AAAABgAAAAAAAAAHZGVwb3NpdAAAAAALAAAAAAAAAARoYXNoAAAD7gAAACAAAAAAAAAADHBlbmFsdHlfcmF0ZQAAAAs=",
+        "AAAAAgAAAFlTdG9yYWdlIGtleXMgYWRkZWQgYWZ0ZXIgYERhdGFLZXlgIHJlYWNoZWQgdGhlIDUwIHZhcmlhbnRzIGEgY29udHJhY3QKdHlwZSBlbnVtIGNhbiBob2xkLgAAAAAAAAAAAAAKRGF0YUtleUV4dAAAAAAAAwAAAAAAAAAAAAAAD1BlbmRpbmdGZWVCYXNpcwAAAAABAAAAAAAAABBSZXNvbHZlZENoaWxkcmVuAAAAAQAAAAYAAAABAAAAAAAAAAhSZWZlcnJlZAAAAAIAAAATAAAABg==",
+        "AAAAAQAAAFtCYWxhbmNlZCBzZWVkIGxpcXVpZGl0eSBhIHByb3ZpZGVyIHB1dCBpbnRvIGEgbWFya2V0LiBUaGUgcG9zaXRpb24ncwpzaGFyZXMgYXJlIGB5ZXMgKyBub2AuAAAAAAAAAAAKTHBQb3NpdGlvbgAAAAAABAAAAAAAAAAOZmVlc193aXRoZHJhd24AAAAAAAsAAAAAAAAAAm5vAAAAAAALAAAAAAAAABNwcmluY2lwYWxfd2l0aGRyYXduAAAAAAEAAAAAAAAAA3llcwAAAAAL",
+        "AAAAAQAAAENBIHVzZXIncyByZXBvcnQgb2YgYSBtYXJrZXQsIGUuZy4gYSBtaXNsZWFkaW5nIG9yIGFidXNpdmUgcXVlc3Rpb24uAAAAAAAAAAAKTWFya2V0RmxhZwAAAAAAAgAAAAAAAAAKZmxhZ2dlZF9hdAAAAAAABgAAAAAAAAAGcmVhc29uAAAAAAAQ",
+        "AAAAAQAAAEZJbXBsaWVkIHByb2JhYmlsaXR5IG9mIGVhY2ggc2lkZSwgZnJvbSB0aGUgc2hhcmUgb2YgdGhlIHBvb2wgaXQgaG9sZHMuAAAAAAAAAAAACk1hcmtldE9kZHMAAAAAAAIAAAAAAAAAAm5vAAAAAAALAAAAAAAAAAN5ZXMAAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAAClByZWRpY3Rpb24AAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAEc2lkZQAAB9AAAAAOUHJlZGljdGlvblNpZGUAAAAAAAAAAAAId2VpZ2h0ZWQAAAAL",
+        "AAAAAQAAACJIb3cgYW5kIHdoeSBhIG1hcmtldCB3YXMgcmVzb2x2ZWQuAAAAAAAAAAAAClJlc29sdXRpb24AAAAAAAQAAAAAAAAACGV2aWRlbmNlAAAH0AAAABJSZXNvbHV0aW9uRXZpZGVuY2UAAAAAAAAAAAALcmVzb2x2ZWRfYXQAAAAABgAAAAAAAAAIcmVzb2x2ZXIAAAATAAAAAAAAAAx3aW5uaW5nX3NpZGUAAAfQAAAADlByZWRpY3Rpb25TaWRlAAA=",
+        "AAAAAgAAADZBIHNlbnNpdGl2ZSBhZG1pbiBhY3Rpb24gdGhhdCBuZWVkcyBtdWx0aXNpZyBhcHByb3ZhbC4AAAAAAAAAAAALQWRtaW5BY3Rpb24AAAAAFgAAAAEAAAAAAAAAC1VwZGF0ZUFkbWluAAAAAAEAAAATAAAAAQAAAAAAAAASU2V0UGxhdGZvcm1GZWVSYXRlAAAAAAABAAAACwAAAAEAAAAAAAAADVJlc29sdmVNYXJrZXQAAAAAAAADAAAABgAAB9AAAAAOUHJlZGljdGlvblNpZGUAAAAAB9AAAAASUmVzb2x1dGlvbkV2aWRlbmNlAAAAAAABAAAAAAAAAApTZXRTaWduZXJzAAAAAAACAAAD6gAAABMAAAAEAAAAAQAAAAAAAAALU2V0UmVzb2x2ZXIAAAAAAQAAABMAAAABAAAAAAAAAAtTZXRUcmVhc3VyeQAAAAABAAAAEwAAAAEAAAAAAAAADlN3ZWVwVW5jbGFpbWVkAAAAAAACAAAABgAAABMAAAABAAAAAAAAAA9JbnN1cmFuY2VQYXlvdXQAAAAAAgAAAAYAAAPqAAAH0AAAAAxDb21wZW5zYXRpb24AAAABAAAAAAAAAAtTZXRGZWVCYXNpcwAAAAABAAAH0AAAAAhGZWVCYXNpcwAAAAEAAAAAAAAAFFNldENvbW1pdFBlbmFsdHlSYXRlAAAAAQAAAAsAAAABAAAAAAAAAA5TZXRDbGFpbVdpbmRvdwAAAAAAAQAAAAYAAAABAAAAAAAAABNTZXRSZXNvbHV0aW9uV2luZG93AAAAAAEAAAAGAAAAAQAAAAAAAAAMU2V0TW9kZXJhdG9yAAAAAgAAABMAAAABAAAAAQAAAAAAAAAQU2V0S2VlcGVyVGlwUmF0ZQAAAAEAAAALAAAAAQAAAAAAAAANU2V0THBGZWVTaGFyZQAAAAAAAAEAAAALAAAAAQAAAAAAAAAQU2V0UmVmZXJyYWxTaGFyZQAAAAEAAAALAAAAAQAAAAAAAAARU2V0SW5zdXJhbmNlU2hhcmUAAAAAAAABAAAACwAAAAEAAAAAAAAAD1NldEluc3VyYW5jZUNhcAAAAAABAAAACwAAAAEAAAAAAAAAC1NldEZlZVRpZXJzAAAAAAIAAAfQAAAAC0ZlZVRpZXJLaW5kAAAAA+oAAAfQAAAAB0ZlZVRpZXIAAAAAAQAAAAAAAAARU2V0Q3JlYXRpb25Qb2xpY3kAAAAAAAABAAAH0AAAAA5DcmVhdGlvblBvbGljeQAAAAAAAQAAAAAAAAAOU2V0QWxsb3dsaXN0ZWQAAAAAAAIAAAATAAAAAQAAAAEAAAAAAAAAF1NldE1pbkNyZWF0b3JSZXB1dGF0aW9uAAAAAAEAAAAE",
+        "AAAAAQAAAAAAAAAAAAAAC0NsYWltUmVzdWx0AAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAC0NsYWltU3RhdHVzAA==",
+        "AAAAAgAAACxPdXRjb21lIG9mIGEgc2luZ2xlIG1hcmtldCBpbiBhIGJhdGNoIGNsYWltLgAAAAAAAAALQ2xhaW1TdGF0dXMAAAAABwAAAAAAAAAAAAAABFBhaWQAAAAAAAAAAAAAAAhSZWZ1bmRlZAAAAAAAAAAAAAAADE5vUHJlZGljdGlvbgAAAAAAAAAAAAAAC05vdFJlc29sdmVkAAAAAAAAAAAAAAAACU5vdFdpbm5lcgAAAAAAAAAAAAAAAAAAEUNsYWltV2luZG93Q2xvc2VkAAAAAAAAAAAAAAAAAAAJQ29udGVzdGVkAAAA",
+        "AAAAAgAAACJXaGF0IGEgZmVlIHRpZXIgdGFibGUgaXMga2V5ZWQgb24uAAAAAAAAAAAAC0ZlZVRpZXJLaW5kAAAAAAIAAAAAAAAAAAAAAAZWb2x1bWUAAAAAAAAAAAAAAAAABVN0YWtlAAAA",
+        "AAAAAQAAADdDb21wZW5zYXRpb24gb3dlZCB0byBvbmUgdXNlciBvZiBhIG1pcy1yZXNvbHZlZCBtYXJrZXQuAAAAAAAAAAAMQ29tcGVuc2F0aW9uAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAlyZWNpcGllbnQAAAAAAAAT",
+        "AAAAAQAAAChXaGF0IGEgY3JlYXRvciBjaG9vc2VzIGZvciBhIG5ldyBtYXJrZXQuAAAAAAAAAAxNYXJrZXRQYXJhbXMAAAAHAAAAAAAAAA1jb21taXRfcmV2ZWFsAAAAAAAAAQAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAALZWFybHlfYm9udXMAAAAACwAAAAAAAAAIbWV0YWRhdGEAAAfQAAAADk1hcmtldE1ldGFkYXRhAAAAAAAAAAAACHF1ZXN0aW9uAAAAEAAAAAAAAAAIc2NoZWR1bGUAAAfQAAAADk1hcmtldFNjaGVkdWxlAAAAAAAAAAAABXRva2VuAAAAAAAAEw==",
+        "AAAAAQAAAMNUZW1wbGF0ZSBmb3IgYSBxdWVzdGlvbiB0aGF0IGlzIGFza2VkIGFnYWluIG9uIGEgZml4ZWQgY2FkZW5jZS4gQW55b25lIGNhbgpyb2xsIHRoZSBzZXJpZXMgdG8gb3BlbiB0aGUgbmV4dCByb3VuZCBvbmNlIHRoZSBwcmV2aW91cyBvbmUgaGFzIGVuZGVkLgpUaGUgcXVlc3Rpb24gaXMgcmV1c2VkIHZlcmJhdGltIGZvciBldmVyeSByb3VuZC4AAAAAAAAAAAxNYXJrZXRTZXJpZXMAAAAKAAAAAAAAAAdjYWRlbmNlAAAAAAYAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAARY3VycmVudF9tYXJrZXRfaWQAAAAAAAPoAAAABgAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAIZHVyYXRpb24AAAAGAAAAAAAAAA5sYXN0X3JvbGxlZF9hdAAAAAAABgAAAAAAAAAIbWV0YWRhdGEAAAfQAAAADk1hcmtldE1ldGFkYXRhAAAAAAAAAAAACHF1ZXN0aW9uAAAAEAAAAAAAAAAGcm91bmRzAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAAAQAAADVXaGF0IGEgY3JlYXRvciBjaG9vc2VzIGZvciBhIHJlY3VycmluZyBtYXJrZXQgc2VyaWVzLgAAAAAAAAAAAAAMU2VyaWVzUGFyYW1zAAAABgAAAAAAAAAHY2FkZW5jZQAAAAAGAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAhkdXJhdGlvbgAAAAYAAAAAAAAACG1ldGFkYXRhAAAH0AAAAA5NYXJrZXRNZXRhZGF0YQAAAAAAAAAAAAhxdWVzdGlvbgAAABAAAAAAAAAABXRva2VuAAAAAAAAEw==",
+        "AAAABAAAACdXaHkgYGNyZWF0ZV9tYXJrZXRgIHJlamVjdGVkIGEgY3JlYXRvci4AAAAAAAAAAA1DcmVhdGlvbkVycm9yAAAAAAAAAwAAAAAAAAAJQWRtaW5Pbmx5AAAAAAAAAQAAAAAAAAAOTm90QWxsb3dsaXN0ZWQAAAAAAAIAAAAAAAAAFkluc3VmZmljaWVudFJlcHV0YXRpb24AAAAAAAM=",
+        "AAAAAQAAADNBdHRyaWJ1dGlvbiBhbmQgcmV3YXJkcyBvZiBhIHJlZmVycmVyIGluIG9uZSB0b2tlbi4AAAAAAAAAAA1SZWZlcnJlclN0YXRzAAAAAAAABAAAAAAAAAAHY2xhaW1lZAAAAAALAAAAAAAAAAZlYXJuZWQAAAAAAAsAAAAAAAAACHJlZmVycmVkAAAABAAAAAAAAAAGdm9sdW1lAAAAAAAL",
+        "AAAABQAAADtFbWl0dGVkIGZvciBldmVyeSB1c2VyIGNvbXBlbnNhdGVkIGZyb20gdGhlIGluc3VyYW5jZSBmdW5kLgAAAAAAAAAADUluc3VyYW5jZVBhaWQAAAAAAAABAAAADmluc3VyYW5jZV9wYWlkAAAAAAADAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAE9FbWl0dGVkIHdoZW4gYW4gdW5yZXNvbHZlZCBtYXJrZXQgaXMgaW52YWxpZGF0ZWQgYWZ0ZXIgaXRzIHJlc29sdXRpb24gZGVhZGxpbmUuAAAAAAAAAAANTWFya2V0RXhwaXJlZAAAAAAAAAEAAAAObWFya2V0X2V4cGlyZWQAAAAAAAIAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAABAAAAAAAAABByZXNvbHZlX2RlYWRsaW5lAAAABgAAAAAAAAAC",
+        "AAAABQAAACNFbWl0dGVkIHdoZW4gYSB1c2VyIGZsYWdzIGEgbWFya2V0LgAAAAAAAAAADU1hcmtldEZsYWdnZWQAAAAAAAABAAAADm1hcmtldF9mbGFnZ2VkAAAAAAAEAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAAAAAAAAZyZWFzb24AAAAAABAAAAAAAAAAAAAAAAVmbGFncwAAAAAAAAQAAAAAAAAAAg==",
+        "AAAAAgAAADhXaG8gbWF5IGNyZWF0ZSBtYXJrZXRzIGFuZCBzZXJpZXMuIFRoZSBhZG1pbiBhbHdheXMgY2FuLgAAAAAAAAAOQ3JlYXRpb25Qb2xpY3kAAAAAAAMAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAJQWxsb3dsaXN0AAAAAAAAAAAAAAAAAAAJQWRtaW5Pbmx5AAAA",
+        "AAAAAQAAAGJEZXNjcmlwdGl2ZSBkYXRhIHNob3duIGJ5IGZyb250ZW5kcywgZWRpdGFibGUgYnkgdGhlIGNyZWF0b3IgdW50aWwgdGhlCmZpcnN0IHByZWRpY3Rpb24gaXMgcGxhY2VkLgAAAAAAAAAAAA5NYXJrZXRNZXRhZGF0YQAAAAAABAAAAAAAAAAIY2F0ZWdvcnkAAAAQAAAAAAAAAAlpbWFnZV91cmkAAAAAAAAQAAAAAAAAABFyZXNvbHV0aW9uX3NvdXJjZQAAAAAAABAAAAAAAAAABHRhZ3MAAAPqAAAAEA==",
+        "AAAAAQAAAIpXaGVuIGEgbWFya2V0IHN0b3BzIHRha2luZyBwcmVkaWN0aW9ucyBhbmQgd2hlbiBpdCBtYXkgYmUgcmVzb2x2ZWQuCkJldHRpbmcgdXN1YWxseSBjbG9zZXMgYmVmb3JlIHRoZSBldmVudCwgd2l0aCB0aGUgb3V0Y29tZSBrbm93biBsYXRlci4AAAAAAAAAAAAOTWFya2V0U2NoZWR1bGUAAAAAAAMAAAAAAAAACmNsb3NlX3RpbWUAAAAAAAYAAAAAAAAADXJlc29sdmVfYWZ0ZXIAAAAAAAAGAAAAAAAAABByZXNvbHZlX2RlYWRsaW5lAAAD6AAAAAY=",
+        "AAAAAQAAADFBIHBsYXRmb3JtIGZlZSBpbmNyZWFzZSB3YWl0aW5nIG91dCBpdHMgdGltZWxvY2suAAAAAAAAAAAAAA5QZW5kaW5nRmVlUmF0ZQAAAAAAAgAAAAAAAAAMZWZmZWN0aXZlX2F0AAAABgAAAAAAAAAEcmF0ZQAAAAs=",
         "AAAAAgAAAAAAAAAAAAAADlByZWRpY3Rpb25TaWRlAAAAAAACAAAAAAAAAAAAAAADWWVzAAAAAAAAAAAAAAAAAk5vAAA=",
-        "AAAAAQAAAAAAAAAAAAAAClByZWRpY3Rpb24AAAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAEc2lkZQAAB9AAAAAOUHJlZGljdGlvblNpZGUAAA==",
-        "AAAAAQAAAAAAAAAAAAAABk1hcmtldAAAAAAACQAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAhlbmRfdGltZQAAAAYAAAAAAAAACHF1ZXN0aW9uAAAAEAAAAAAAAAAIcmVzb2x2ZWQAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAACHRvdGFsX25vAAAACwAAAAAAAAAJdG90YWxfeWVzAAAAAAAACw==",
+        "AAAAAAAAADNTdGFrZSBnb3Zlcm5hbmNlIHRva2VucyB0b3dhcmRzIGEgZmVlIGRpc2NvdW50IHRpZXIAAAAABXN0YWtlAAAAAAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAABQAAAAAAAAAAAAAADkFjdGlvbkFwcHJvdmVkAAAAAAABAAAAD2FjdGlvbl9hcHByb3ZlZAAAAAADAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAAAAAAJYXBwcm92YWxzAAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAEhFbWl0dGVkIHdoZW4gYSBwcm9wb3NhbCByZWFjaGVzIGl0cyB0aHJlc2hvbGQgYW5kIHRoZSBhY3Rpb24gaXMgYXBwbGllZC4AAAAAAAAADkFjdGlvbkV4ZWN1dGVkAAAAAAABAAAAD2FjdGlvbl9leGVjdXRlZAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAI=",
+        "AAAABQAAADhFbWl0dGVkIHdoZW4gYSBzaWduZXIgcHJvcG9zZXMgYSBzZW5zaXRpdmUgYWRtaW4gYWN0aW9uLgAAAAAAAAAOQWN0aW9uUHJvcG9zZWQAAAAAAAEAAAAPYWN0aW9uX3Byb3Bvc2VkAAAAAAQAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAABAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAALQWRtaW5BY3Rpb24AAAAAAAAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAENFbWl0dGVkIHdoZW4gdGhlIHBsYXRmb3JtIGZlZSByYXRlIGNoYW5nZXMgaW1tZWRpYXRlbHkgKGRlY3JlYXNlcykuAAAAAAAAAAAORmVlUmF0ZUNoYW5nZWQAAAAAAAEAAAAQZmVlX3JhdGVfY2hhbmdlZAAAAAEAAAAAAAAABHJhdGUAAAALAAAAAAAAAAI=",
+        "AAAABQAAACpFbWl0dGVkIHdoZW4gYSBtb2RlcmF0b3IgZGVsaXN0cyBhIG1hcmtldC4AAAAAAAAAAAAOTWFya2V0RGVsaXN0ZWQAAAAAAAEAAAAPbWFya2V0X2RlbGlzdGVkAAAAAAQAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAABAAAAAAAAAAltb2RlcmF0b3IAAAAAAAATAAAAAAAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAAAAAAAAAAAIcmVmdW5kZWQAAAABAAAAAAAAAAI=",
+        "AAAABQAAACJFbWl0dGVkIHdoZW4gYSBtYXJrZXQgaXMgcmVzb2x2ZWQuAAAAAAAAAAAADk1hcmtldFJlc29sdmVkAAAAAAABAAAAD21hcmtldF9yZXNvbHZlZAAAAAAEAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAAAAAAAAMd2lubmluZ19zaWRlAAAH0AAAAA5QcmVkaWN0aW9uU2lkZQAAAAAAAAAAAAAAAAAIcmVzb2x2ZXIAAAATAAAAAAAAAAAAAAAIZXZpZGVuY2UAAAfQAAAAElJlc29sdXRpb25FdmlkZW5jZQAAAAAAAAAAAAI=",
+        "AAAABQAAAFNFbWl0dGVkIHdoZW4gbGVmdG92ZXIgY29sbGF0ZXJhbCBpcyBzd2VwdCBvdXQgb2YgYSBtYXJrZXQgYWZ0ZXIgaXRzIGNsYWltIGRlYWRsaW5lLgAAAAAAAAAADlVuY2xhaW1lZFN3ZXB0AAAAAAABAAAAD3VuY2xhaW1lZF9zd2VwdAAAAAADAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAAAAAAAACdG8AAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAAAgAAAKtUaWVzIGEgY29uZGl0aW9uYWwgbWFya2V0IHRvIHRoZSBvdXRjb21lIG9mIGEgcGFyZW50IG1hcmtldC4gSWYgdGhlIHBhcmVudApyZXNvbHZlcyB0byBhIGRpZmZlcmVudCBvdXRjb21lIHRoZSBjb25kaXRpb25hbCBtYXJrZXQgaXMgY2FuY2VsbGVkIGFuZApldmVyeSBzdGFrZSBpcyByZWZ1bmRlZC4AAAAAAAAAAA9NYXJrZXRDb25kaXRpb24AAAAAAgAAAAAAAAAAAAAABE5vbmUAAAABAAAAAAAAAAZQYXJlbnQAAAAAAAIAAAAGAAAH0AAAAA5QcmVkaWN0aW9uU2lkZQAA",
+        "AAAAAQAAAD1BIHN3aXRjaCB0byB0aGUgZ3Jvc3MgZmVlIGJhc2lzIHdhaXRpbmcgb3V0IHRoZSBmZWUgdGltZWxvY2suAAAAAAAAAAAAAA9QZW5kaW5nRmVlQmFzaXMAAAAAAgAAAAAAAAAFYmFzaXMAAAAAAAfQAAAACEZlZUJhc2lzAAAAAAAAAAxlZmZlY3RpdmVfYXQAAAAG",
+        "AAAABQAAAEBFbWl0dGVkIHdoZW4gdGhlIGZlZSBiYXNpcyBjaGFuZ2VzIGltbWVkaWF0ZWx5IChiYWNrIHRvIHByb2ZpdCkuAAAAAAAAAA9GZWVCYXNpc0NoYW5nZWQAAAAAAQAAABFmZWVfYmFzaXNfY2hhbmdlZAAAAAAAAAEAAAAAAAAABWJhc2lzAAAAAAAH0AAAAAhGZWVCYXNpcwAAAAAAAAAC",
+        "AAAAAQAAAChQYXlvdXQgYWNjb3VudGluZyBmb3IgYSByZXNvbHZlZCBtYXJrZXQuAAAAAAAAABBNYXJrZXRTZXR0bGVtZW50AAAACAAAAAAAAAAOY2xhaW1fZGVhZGxpbmUAAAAAA+gAAAAGAAAAAAAAAA1jbGFpbWVkX3N0YWtlAAAAAAAACwAAAAAAAAALZGlzdHJpYnV0ZWQAAAAACwAAAAAAAAAKZHVzdF9zd2VwdAAAAAAACwAAAAAAAAAOaW5zdXJhbmNlX3BhaWQAAAAAAAsAAAAAAAAAB2xwX2ZlZXMAAAAACwAAAAAAAAALcmVzb2x2ZWRfYXQAAAAABgAAAAAAAAAPdW5jbGFpbWVkX3N3ZXB0AAAAAAs=",
+        "AAAAAQAAACNCb3VuZHMgYSB1c2VyIHB1dHMgb24gYSBwcmVkaWN0aW9uLgAAAAAAAAAAEFByZWRpY3Rpb25MaW1pdHMAAAACAAAAAAAAAAhkZWFkbGluZQAAAAQAAAAAAAAACm1pbl9wYXlvdXQAAAAAAAs=",
+        "AAAAAAAAAJ9QbGFjZSBhIHByZWRpY3Rpb24gb24gYSBtYXJrZXQuIGBsaW1pdHNgIHJlamVjdHMgdGhlIGJldCBpZgpgcXVvdGVfcGF5b3V0YCBmb3IgaXQgaGFzIGRyb3BwZWQgYmVsb3cgYG1pbl9wYXlvdXRgLCBvciBpZiB0aGUgbGVkZ2VyCnNlcXVlbmNlIGlzIHBhc3QgYGRlYWRsaW5lYC4AAAAAB3ByZWRpY3QAAAAABgAAAAAAAAAEdXNlcgAAABMAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAAAAAABHNpZGUAAAfQAAAADlByZWRpY3Rpb25TaWRlAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAIcmVmZXJyZXIAAAPoAAAAEwAAAAAAAAAGbGltaXRzAAAAAAPoAAAH0AAAABBQcmVkaWN0aW9uTGltaXRzAAAAAA==",
+        "AAAAAAAAADxXaXRoZHJhdyBzdGFrZWQgZ292ZXJuYW5jZSB0b2tlbnMgb25jZSB0aGUgbG9ja3VwIGhhcyBwYXNzZWQAAAAHdW5zdGFrZQAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAEEFkbWluVHJhbnNmZXJyZWQAAAABAAAAEWFkbWluX3RyYW5zZmVycmVkAAAAAAAAAgAAAAAAAAAOcHJldmlvdXNfYWRtaW4AAAAAABMAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAAAAAAI=",
+        "AAAAAAAAAB1HZXQgYSB1c2VyJ3MgZmxhZyBvbiBhIG1hcmtldAAAAAAAAAhnZXRfZmxhZwAAAAIAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAAAAAABHVzZXIAAAATAAAAAQAAA+gAAAfQAAAACk1hcmtldEZsYWcAAA==",
+        "AAAAAAAAAFBHZXQgdGhlIGltcGxpZWQgcHJvYmFiaWxpdHkgb2YgZWFjaCBzaWRlIGluIGJhc2lzIHBvaW50cy4gQW4gZW1wdHkKcG9vbCBpcyBldmVuLgAAAAhnZXRfb2RkcwAAAAEAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAABAAAH0AAAAApNYXJrZXRPZGRzAAA=",
+        "AAAAAQAAAD1XaGF0IGEgcmVzb2x2ZXIgYXR0YWNoZXMgdG8gYW4gb3V0Y29tZSBzbyB1c2VycyBjYW4gYXVkaXQgaXQuAAAAAAAAAAAAABJSZXNvbHV0aW9uRXZpZGVuY2UAAAAAAAIAAAAAAAAADWp1c3RpZmljYXRpb24AAAAAAAAQAAAAAAAAAAlyZWZlcmVuY2UAAAAAAAAQ",
+        "AAAAAAAAAMdUcmlnZ2VyIGEgcGF5b3V0IG9uIGJlaGFsZiBvZiBhIHdpbm5lci4gV2lubmluZ3MgYWx3YXlzIGdvIHRvIGB1c2VyYCwKYGNhbGxlcmAgb25seSByZWNlaXZlcyB0aGUga2VlcGVyIHRpcCBjb25maWd1cmVkIGJ5IHRoZSBhZG1pbi4gUmVmdW5kcwpvZiBjYW5jZWxsZWQgbWFya2V0cyBhcmUgcGFzc2VkIG9uIGluIGZ1bGwsIHdpdGhvdXQgYSB0aXAuAAAAAAljbGFpbV9mb3IAAAAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABHVzZXIAAAATAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAAAs=",
+        "AAAAAAAAABFHZXQgYWRtaW4gYWRkcmVzcwAAAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAALpDbGFpbSB3aW5uaW5ncyBmcm9tIHNldmVyYWwgcmVzb2x2ZWQgbWFya2V0cyB3aXRoIGEgc2luZ2xlIGF1dGguCk1hcmtldHMgdGhhdCBjYW4ndCBiZSBjbGFpbWVkIGFyZSBza2lwcGVkIGFuZCByZXBvcnRlZCBpbiB0aGUgcmVzdWx0LApwYXlvdXRzIGFyZSBhZ2dyZWdhdGVkIGludG8gb25lIHRyYW5zZmVyIHBlciB0b2tlbi4AAAAAAApjbGFpbV9tYW55AAAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAKbWFya2V0X2lkcwAAAAAD6gAAAAYAAAABAAAD6gAAB9AAAAALQ2xhaW1SZXN1bHQA",
+        "AAAAAAAAABJHZXQgbWFya2V0IGRldGFpbHMAAAAAAApnZXRfbWFya2V0AAAAAAABAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAB9AAAAAGTWFya2V0AAA=",
+        "AAAAAAAAABNHZXQgYSBtYXJrZXQgc2VyaWVzAAAAAApnZXRfc2VyaWVzAAAAAAABAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAGAAAAAQAAB9AAAAAMTWFya2V0U2VyaWVz",
         "AAAAAAAAAC1Jbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIGFuIGFkbWluIGFkZHJlc3MAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAA==",
-        "AAAAAAAAAB5DcmVhdGUgYSBuZXcgcHJlZGljdGlvbiBtYXJrZXQAAAAAAA1jcmVhdGVfbWFya2V0AAAAAAAABQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAhxdWVzdGlvbgAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAAQAAAAY=",
-        "AAAAAAAAAB5QbGFjZSBhIHByZWRpY3Rpb24gb24gYSBtYXJrZXQAAAAAAAdwcmVkaWN0AAAAAAQAAAAAAAAABHVzZXIAAAATAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAAAAAARzaWRlAAAH0AAAAA5QcmVkaWN0aW9uU2lkZQAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-        "AAAAAAAAADVSZXNvbHZlIGEgbWFya2V0IChhZG1pbiBvbmx5KSBhbmQgZGlzdHJpYnV0ZSB3aW5uaW5ncwAAAAAAAA5yZXNvbHZlX21hcmtldAAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAAAAAAx3aW5uaW5nX3NpZGUAAAfQAAAADlByZWRpY3Rpb25TaWRlAAAAAAAA",
+        "AAAAAAAAAM9Nb3ZlIHRoZSByb3VuZGluZyByZW1haW5kZXIgb2YgYSByZXNvbHZlZCBtYXJrZXQgaW50byBwbGF0Zm9ybSBmZWVzLgpDYWxsYWJsZSBieSBhbnlvbmUgb25jZSBldmVyeSB3aW5uaW5nIHN0YWtlIGhhcyBiZWVuIGNsYWltZWQuIFdpbm5pbmdzCmxlZnQgYWZ0ZXIgdGhlIGNsYWltIGRlYWRsaW5lIGdvIHRocm91Z2ggYHN3ZWVwX3VuY2xhaW1lZGAgaW5zdGVhZC4AAAAACnN3ZWVwX2R1c3QAAAAAAAEAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAABAAAACw==",
+        "AAAABQAAAENFbWl0dGVkIHdoZW4gdGhlIHJlc29sdmVyIHB1dHMgYSBtYXJrZXQncyByZXNvbHV0aW9uIHRvIGEgbmV3IHZvdGUuAAAAAAAAAAATUmVzb2x1dGlvbkNvbnRlc3RlZAAAAAABAAAAFHJlc29sdXRpb25fY29udGVzdGVkAAAAAwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAAAAAAACHJlc29sdmVyAAAAEwAAAAAAAAAAAAAADHdpbm5pbmdfc2lkZQAAB9AAAAAOUHJlZGljdGlvblNpZGUAAAAAAAAAAAAC",
+        "AAAAAAAAAGpGbGFnIGEgbWFya2V0IGZvciBtb2RlcmF0b3JzIHRvIHJldmlldy4gRWFjaCB1c2VyIGNhbiBmbGFnIGEgbWFya2V0Cm9uY2U7IHJldHVybnMgdGhlIG1hcmtldCdzIGZsYWcgY291bnQuAAAAAAALZmxhZ19tYXJrZXQAAAAAAwAAAAAAAAAEdXNlcgAAABMAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAAAAAABnJlYXNvbgAAAAAAEAAAAAEAAAAE",
+        "AAAAAAAAADdHZXQgdGhlIG11bHRpc2lnIHNpZ25lcnMsIGVtcHR5IGlmIG11bHRpc2lnIGlzIGRpc2FibGVkAAAAAAtnZXRfc2lnbmVycwAAAAAAAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAIpPcGVuIHRoZSBuZXh0IG1hcmtldCBvZiBhIHNlcmllcy4gQ2FsbGFibGUgYnkgYW55b25lIG9uY2UgdGhlIHByZXZpb3VzCnJvdW5kIGhhcyBlbmRlZCBhbmQgYSBmdWxsIGNhZGVuY2UgaGFzIGVsYXBzZWQgc2luY2UgaXQgd2FzIG9wZW5lZC4AAAAAAAtyb2xsX3NlcmllcwAAAAABAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAGAAAAAQAAAAY=",
+        "AAAAAAAAAIBFbmFibGUgbXVsdGlzaWcgYXBwcm92YWwgZm9yIHNlbnNpdGl2ZSBhZG1pbiBhY3Rpb25zIChhZG1pbiBvbmx5KS4KT25jZSBlbmFibGVkLCBzaWduZXJzIGNoYW5nZSB0aHJvdWdoIGEgYFNldFNpZ25lcnNgIHByb3Bvc2FsLgAAAAtzZXRfc2lnbmVycwAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAB3NpZ25lcnMAAAAD6gAAABMAAAAAAAAACXRocmVzaG9sZAAAAAAAAAQAAAAA",
+        "AAAAAAAAADZBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHRyYW5zZmVyIChub21pbmF0ZWQgYWRtaW4gb25seSkAAAAAAAxhY2NlcHRfYWRtaW4AAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAAIBGb3J3YXJkIGEgdG9rZW4ncyBjb2xsZWN0ZWQgZmVlcyAoZS5nLiBzd2VwdCBkdXN0KSB0byB0aGUgdHJlYXN1cnkuCkNhbGxhYmxlIGJ5IGFueW9uZSwgY2xhaW1zIGZvcndhcmQgdGhlaXIgZmVlcyBhdXRvbWF0aWNhbGx5LgAAAAxmb3J3YXJkX2ZlZXMAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAA5HZXQgYSBwcm9wb3NhbAAAAAAADGdldF9wcm9wb3NhbAAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAABAAAH0AAAAAhQcm9wb3NhbA==",
+        "AAAAAAAAAEFHZXQgdGhlIHJlZmVycmVyIGEgdXNlcidzIHByZWRpY3Rpb24gb24gYSBtYXJrZXQgaXMgYXR0cmlidXRlZCB0bwAAAAAAAAxnZXRfcmVmZXJyZXIAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAD1HZXQgdGhlIGNvbnRyYWN0IGFsbG93ZWQgdG8gcmVzb2x2ZSBtYXJrZXRzIGJlc2lkZXMgdGhlIGFkbWluAAAAAAAADGdldF9yZXNvbHZlcgAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAC9HZXQgdGhlIHRyZWFzdXJ5IHBsYXRmb3JtIGZlZXMgYXJlIGZvcndhcmRlZCB0bwAAAAAMZ2V0X3RyZWFzdXJ5AAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAACtDaGVjayB3aGV0aGVyIGFuIGFkZHJlc3MgY2FuIGRlbGlzdCBtYXJrZXRzAAAAAAxpc19tb2RlcmF0b3IAAAABAAAAAAAAAAltb2RlcmF0b3IAAAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAKlRdW90ZSB0aGUgcGF5b3V0LCBhZnRlciB0aGUgcGxhdGZvcm0gZmVlLCBvZiBhIG5ldyBgYW1vdW50YCBzdGFrZSBvbgpgc2lkZWAgaWYgdGhlIG1hcmtldCByZXNvbHZlZCB0aGF0IHdheSByaWdodCBhZnRlciBpdCB3YXMgcGxhY2VkLgpGZWUgdGllciBkaXNjb3VudHMgYXJlbid0IGFwcGxpZWQuAAAAAAAADHF1b3RlX3BheW91dAAAAAMAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAAAAAABHNpZGUAAAfQAAAADlByZWRpY3Rpb25TaWRlAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAAL",
+        "AAAAAAAAAINMZXQgYSBjb250cmFjdCwgZS5nLiB0aGUgZ292ZXJuYW5jZSB0b2tlbidzIGRpc3B1dGUgdm90ZSwgcmVzb2x2ZQptYXJrZXRzIChhZG1pbiBvbmx5LCB0aHJvdWdoIGEgcHJvcG9zYWwgd2hlbiBtdWx0aXNpZyBpcyBlbmFibGVkKQAAAAAMc2V0X3Jlc29sdmVyAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhyZXNvbHZlcgAAABMAAAAA",
+        "AAAAAAAAAGlTZXQgdGhlIHRyZWFzdXJ5IHBsYXRmb3JtIGZlZXMgYXJlIGZvcndhcmRlZCB0byAoYWRtaW4gb25seSwgdGhyb3VnaAphIHByb3Bvc2FsIHdoZW4gbXVsdGlzaWcgaXMgZW5hYmxlZCkAAAAAAAAMc2V0X3RyZWFzdXJ5AAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAh0cmVhc3VyeQAAABMAAAAA",
+        "AAAAAAAAAHxOb21pbmF0ZSBhIG5ldyBhZG1pbiwgd2hvIHRha2VzIG92ZXIgb25jZSB0aGV5IGNhbGwgYGFjY2VwdF9hZG1pbmAKKGFkbWluIG9ubHksIHRocm91Z2ggYSBwcm9wb3NhbCB3aGVuIG11bHRpc2lnIGlzIGVuYWJsZWQpAAAADHVwZGF0ZV9hZG1pbgAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAABQAAAEZFbWl0dGVkIHdoZW4gdGhlIGFkbWluIG5vbWluYXRlcyBhIHN1Y2Nlc3Nvciwgd2hvIHN0aWxsIGhhcyB0byBhY2NlcHQuAAAAAAAAAAAAFUFkbWluVHJhbnNmZXJQcm9wb3NlZAAAAAAAAAEAAAAXYWRtaW5fdHJhbnNmZXJfcHJvcG9zZWQAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAAAAAAC",
+        "AAAAAAAAAB5DcmVhdGUgYSBuZXcgcHJlZGljdGlvbiBtYXJrZXQAAAAAAA1jcmVhdGVfbWFya2V0AAAAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAZwYXJhbXMAAAAAB9AAAAAMTWFya2V0UGFyYW1zAAAAAQAAAAY=",
+        "AAAAAAAAAElSZWdpc3RlciBhIHJlY3VycmluZyBtYXJrZXQgc2VyaWVzLiBSb3VuZHMgYXJlIG9wZW5lZCB3aXRoIGByb2xsX3Nlcmllc2AuAAAAAAAADWNyZWF0ZV9zZXJpZXMAAAAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABnBhcmFtcwAAAAAH0AAAAAxTZXJpZXNQYXJhbXMAAAABAAAABg==",
+        "AAAAAAAAAJNIaWRlIGEgbWFya2V0IGZyb20gbGlzdCB2aWV3cyAoYWRtaW4gb3IgbW9kZXJhdG9yIG9ubHkpLiBBbgp1bnJlc29sdmVkIG1hcmtldCBpcyBhbHNvIGNhbmNlbGxlZCwgc28gZXZlcnkgcGFydGljaXBhbnQgY2FuIHJlY2xhaW0KdGhlaXIgZnVsbCBzdGFrZS4AAAAADWRlbGlzdF9tYXJrZXQAAAAAAAADAAAAAAAAAAltb2RlcmF0b3IAAAAAAAATAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAAAAAAZyZWFzb24AAAAAABAAAAAA",
+        "AAAAAAAAAIFJbnZhbGlkYXRlIGEgbWFya2V0IG5vYm9keSByZXNvbHZlZCBiZWZvcmUgaXRzIGRlYWRsaW5lLiBDYWxsYWJsZSBieQphbnlvbmU7IGV2ZXJ5IHBhcnRpY2lwYW50IGNhbiB0aGVuIHJlY2xhaW0gdGhlaXIgZnVsbCBzdGFrZS4AAAAAAAANZXhwaXJlX21hcmtldAAAAAAAAAEAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAA",
+        "AAAAAAAAAEJHZXQgd2hhdCBgY2xhaW1fd2lubmluZ3NgIHdvdWxkIHBheSBhIHVzZXIgcmlnaHQgbm93LCAwIGlmIG5vdGhpbmcAAAAAAA1nZXRfY2xhaW1hYmxlAAAAAAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAABAAAACw==",
+        "AAAAAAAAADJHZXQgdGhlIG1vZGVyYXRpb24gZGVjaXNpb24gdGhhdCBkZWxpc3RlZCBhIG1hcmtldAAAAAAADWdldF9kZWxpc3RpbmcAAAAAAAABAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAA+gAAAfQAAAACURlbGlzdGluZwAAAA==",
+        "AAAAAAAAACdHZXQgd2hhdCB0aGUgcGxhdGZvcm0gZmVlIGlzIGNoYXJnZWQgb24AAAAADWdldF9mZWVfYmFzaXMAAAAAAAAAAAAAAQAAB9AAAAAIRmVlQmFzaXM=",
+        "AAAAAAAAABRHZXQgYSBmZWUgdGllciB0YWJsZQAAAA1nZXRfZmVlX3RpZXJzAAAAAAAAAQAAAAAAAAAEa2luZAAAB9AAAAALRmVlVGllcktpbmQAAAAAAQAAA+oAAAfQAAAAB0ZlZVRpZXIA",
+        "AAAAAAAAACxHZXQgdGhlIG51bWJlciBvZiBhcHByb3ZhbHMgYSBwcm9wb3NhbCBuZWVkcwAAAA1nZXRfdGhyZXNob2xkAAAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAIRTZXQgd2hhdCB0aGUgcGxhdGZvcm0gZmVlIGlzIGNoYXJnZWQgb24gKGFkbWluIG9ubHksIHRocm91Z2ggYQpwcm9wb3NhbCB3aGVuIG11bHRpc2lnIGlzIGVuYWJsZWQpLiBTd2l0Y2hpbmcgdG8gZ3Jvc3MgaXMgdGltZWxvY2tlZC4AAAANc2V0X2ZlZV9iYXNpcwAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAFYmFzaXMAAAAAAAfQAAAACEZlZUJhc2lzAAAAAA==",
+        "AAAAAAAAACVSZXBsYWNlIGEgZmVlIHRpZXIgdGFibGUgKGFkbWluIG9ubHkpAAAAAAAADXNldF9mZWVfdGllcnMAAAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABGtpbmQAAAfQAAAAC0ZlZVRpZXJLaW5kAAAAAAAAAAAFdGllcnMAAAAAAAPqAAAH0AAAAAdGZWVUaWVyAAAAAAA=",
+        "AAAAAAAAAC9HcmFudCBvciByZXZva2UgdGhlIG1vZGVyYXRvciByb2xlIChhZG1pbiBvbmx5KQAAAAANc2V0X21vZGVyYXRvcgAAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAJbW9kZXJhdG9yAAAAAAAAEwAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAA==",
+        "AAAAAAAAAF9BcHByb3ZlIGEgcGVuZGluZyBwcm9wb3NhbCAoc2lnbmVycyBvbmx5KS4gVGhlIGFjdGlvbiBleGVjdXRlcyBhcwpzb29uIGFzIHRoZSB0aHJlc2hvbGQgaXMgbWV0LgAAAAAOYXBwcm92ZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAA=",
         "AAAAAAAAACVDbGFpbSB3aW5uaW5ncyBmcm9tIGEgcmVzb2x2ZWQgbWFya2V0AAAAAAAADmNsYWltX3dpbm5pbmdzAAAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAAL",
-        "AAAAAAAAABJHZXQgbWFya2V0IGRldGFpbHMAAAAAAApnZXRfbWFya2V0AAAAAAABAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAB9AAAAAGTWFya2V0AAA=",
-        "AAAAAAAAACtHZXQgdXNlcidzIHByZWRpY3Rpb24gZm9yIGEgc3BlY2lmaWMgbWFya2V0AAAAABNnZXRfdXNlcl9wcmVkaWN0aW9uAAAAAAIAAAAAAAAABHVzZXIAAAATAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAA+gAAAfQAAAAClByZWRpY3Rpb24AAA==",
+        "AAAAAAAAADxHZXQgYSB1c2VyJ3MgdW5yZXZlYWxlZCBjb21taXRtZW50IG9uIGEgY29tbWl0LXJldmVhbCBtYXJrZXQAAAAOZ2V0X2NvbW1pdG1lbnQAAAAAAAIAAAAAAAAABHVzZXIAAAATAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAA+gAAAfQAAAACkNvbW1pdG1lbnQAAA==",
+        "AAAAAAAAACxHZXQgdGhlIG51bWJlciBvZiB1c2VycyB3aG8gZmxhZ2dlZCBhIG1hcmtldAAAAA5nZXRfZmxhZ19jb3VudAAAAAAAAQAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAAE",
+        "AAAAAAAAAEBHZXQgdGhlIG91dGNvbWUgb2YgYSBtYXJrZXQgd2l0aCBpdHMgcmVzb2x2ZXIsIGV2aWRlbmNlIGFuZCB0aW1lAAAADmdldF9yZXNvbHV0aW9uAAAAAAABAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAA+gAAAfQAAAAClJlc29sdXRpb24AAA==",
+        "AAAAAAAAACtDaGVjayB3aGV0aGVyIGEgY3JlYXRvciBpcyBvbiB0aGUgYWxsb3dsaXN0AAAAAA5pc19hbGxvd2xpc3RlZAAAAAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAGBQcm9wb3NlIGEgc2Vuc2l0aXZlIGFkbWluIGFjdGlvbiAoc2lnbmVycyBvbmx5KS4gVGhlIHByb3Bvc2FsIGNvdW50cwphcyB0aGUgcHJvcG9zZXIncyBhcHByb3ZhbC4AAAAOcHJvcG9zZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAAC0FkbWluQWN0aW9uAAAAAAEAAAAG",
+        "AAAAAAAAAIFSZXNvbHZlIGEgbWFya2V0IChhZG1pbiBvciByZXNvbHZlciBvbmx5KSBhbmQgZGlzdHJpYnV0ZSB3aW5uaW5ncy4KVGhlIGV2aWRlbmNlIGlzIHN0b3JlZCB3aXRoIHRoZSBvdXRjb21lLCBzZWUgYGdldF9yZXNvbHV0aW9uYC4AAAAAAAAOcmVzb2x2ZV9tYXJrZXQAAAAAAAQAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAAAAAAMd2lubmluZ19zaWRlAAAH0AAAAA5QcmVkaWN0aW9uU2lkZQAAAAAAAAAAAAhldmlkZW5jZQAAB9AAAAASUmVzb2x1dGlvbkV2aWRlbmNlAAAAAAAA",
+        "AAAABQAAAE5FbWl0dGVkIHdoZW4gYSBzd2l0Y2ggdG8gdGhlIGdyb3NzIGZlZSBiYXNpcyBpcyBzY2hlZHVsZWQgYmVoaW5kIHRoZSB0aW1lbG9jay4AAAAAAAAAAAAXRmVlQmFzaXNDaGFuZ2VTY2hlZHVsZWQAAAAAAQAAABpmZWVfYmFzaXNfY2hhbmdlX3NjaGVkdWxlZAAAAAAAAgAAAAAAAAAFYmFzaXMAAAAAAAfQAAAACEZlZUJhc2lzAAAAAAAAAAAAAAAMZWZmZWN0aXZlX2F0AAAABgAAAAAAAAAC",
+        "AAAAAAAAAC9HZXQgYSBwcm92aWRlcidzIGxpcXVpZGl0eSBwb3NpdGlvbiBpbiBhIG1hcmtldAAAAAAPZ2V0X2xwX3Bvc2l0aW9uAAAAAAIAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAPoAAAH0AAAAApMcFBvc2l0aW9uAAA=",
+        "AAAAAAAAAClHZXQgYSB1c2VyJ3MgY3VtdWxhdGl2ZSBwcmVkaWN0aW9uIHZvbHVtZQAAAAAAAA9nZXRfdXNlcl92b2x1bWUAAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAACw==",
+        "AAAAAAAAAD1BZGQgYSBjcmVhdG9yIHRvIG9yIHJlbW92ZSBpdCBmcm9tIHRoZSBhbGxvd2xpc3QgKGFkbWluIG9ubHkpAAAAAAAAD3NldF9hbGxvd2xpc3RlZAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHYWxsb3dlZAAAAAABAAAAAA==",
+        "AAAAAAAAAHJTd2VlcCBjb2xsYXRlcmFsIGxlZnQgdW5jbGFpbWVkIGFmdGVyIHRoZSBjbGFpbSBkZWFkbGluZSAoYWRtaW4gb25seSwKdGhyb3VnaCBhIHByb3Bvc2FsIHdoZW4gbXVsdGlzaWcgaXMgZW5hYmxlZCkAAAAAAA9zd2VlcF91bmNsYWltZWQAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAAL",
+        "AAAABQAAAD1FbWl0dGVkIHdoZW4gYSBmZWUgaW5jcmVhc2UgaXMgc2NoZWR1bGVkIGJlaGluZCB0aGUgdGltZWxvY2suAAAAAAAAAAAAABhGZWVSYXRlSW5jcmVhc2VTY2hlZHVsZWQAAAABAAAAG2ZlZV9yYXRlX2luY3JlYXNlX3NjaGVkdWxlZAAAAAACAAAAAAAAAARyYXRlAAAACwAAAAAAAAAAAAAADGVmZmVjdGl2ZV9hdAAAAAYAAAAAAAAAAg==",
+        "AAAAAAAAAENHZXQgdGhlIGNsYWltIHdpbmRvdyAoaW4gc2Vjb25kcykgYXBwbGllZCB0byBuZXdseSByZXNvbHZlZCBtYXJrZXRzAAAAABBnZXRfY2xhaW1fd2luZG93AAAAAAAAAAEAAAAG",
+        "AAAAAAAAAExHZXQgdGhlIHNoYXJlIG9mIHBsYXRmb3JtIGZlZXMgcGFpZCB0byBsaXF1aWRpdHkgcHJvdmlkZXJzIChpbiBiYXNpcyBwb2ludHMpAAAAEGdldF9scF9mZWVfc2hhcmUAAAAAAAAAAQAAAAs=",
         "AAAAAAAAABZHZXQgdG90YWwgbWFya2V0IGNvdW50AAAAAAAQZ2V0X21hcmtldF9jb3VudAAAAAAAAAABAAAABg==",
+        "AAAAAAAAABZHZXQgdG90YWwgc2VyaWVzIGNvdW50AAAAAAAQZ2V0X3Nlcmllc19jb3VudAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAB1HZXQgd2lubmluZyBzaWRlIGZvciBhIG1hcmtldAAAAAAAABBnZXRfd2lubmluZ19zaWRlAAAAAQAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAPoAAAH0AAAAA5QcmVkaWN0aW9uU2lkZQAA",
+        "AAAAAAAAAPBDb21wZW5zYXRlIHVzZXJzIG9mIGEgbWFya2V0IGxhdGVyIGZvdW5kIHRvIGJlIG1pcy1yZXNvbHZlZCwgb3V0IG9mCnRoZSBpbnN1cmFuY2UgZnVuZCBvZiB0aGUgbWFya2V0J3MgdG9rZW4gKGFkbWluIG9ubHksIHRocm91Z2ggYQpwcm9wb3NhbCB3aGVuIG11bHRpc2lnIGlzIGVuYWJsZWQpLiBQYXlvdXRzIG9uIG9uZSBtYXJrZXQgYXJlIGNhcHBlZAphdCB0aGUgaW5zdXJhbmNlIGNhcCBzaGFyZSBvZiBpdHMgcG9vbC4AAAAQaW5zdXJhbmNlX3BheW91dAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAAAAAAKcmVjaXBpZW50cwAAAAAD6gAAB9AAAAAMQ29tcGVuc2F0aW9uAAAAAQAAAAs=",
+        "AAAAAAAAADpTZXQgdGhlIGNsYWltIHdpbmRvdyAoYWRtaW4gb25seSksIDAgZGlzYWJsZXMgdGhlIGRlYWRsaW5lAAAAAAAQc2V0X2NsYWltX3dpbmRvdwAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAGd2luZG93AAAAAAAGAAAAAA==",
+        "AAAAAAAAAEdTZXQgdGhlIHNoYXJlIG9mIHBsYXRmb3JtIGZlZXMgcGFpZCB0byBsaXF1aWRpdHkgcHJvdmlkZXJzIChhZG1pbiBvbmx5KQAAAAAQc2V0X2xwX2ZlZV9zaGFyZQAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAFc2hhcmUAAAAAAAALAAAAAA==",
+        "AAAAAAAAAIhDb21taXQgdG8gYSBoaWRkZW4gcHJlZGljdGlvbiBvbiBhIGNvbW1pdC1yZXZlYWwgbWFya2V0LCBlc2Nyb3dpbmcKYGRlcG9zaXRgLiBUaGUgcHJlZGljdGlvbiBvbmx5IGNvdW50cyBvbmNlIHJldmVhbGVkIGFmdGVyIGNsb3NlX3RpbWUuAAAAEWNvbW1pdF9wcmVkaWN0aW9uAAAAAAAABAAAAAAAAAAEdXNlcgAAABMAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAAAAAABGhhc2gAAAPuAAAAIAAAAAAAAAAHZGVwb3NpdAAAAAALAAAAAA==",
+        "AAAAAAAAAEdHZXQgdGhlIHBlci1pbmNpZGVudCBpbnN1cmFuY2UgY2FwIChpbiBiYXNpcyBwb2ludHMgb2YgYSBtYXJrZXQncyBwb29sKQAAAAARZ2V0X2luc3VyYW5jZV9jYXAAAAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAADBHZXQgdGhlIG5vbWluYXRlZCBhZG1pbiB0aGF0IGhhc24ndCBhY2NlcHRlZCB5ZXQAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAACxHZXQgdGhlIGdvdmVybmFuY2UgdG9rZW4gYWNjZXB0ZWQgYnkgYHN0YWtlYAAAABFnZXRfc3Rha2luZ190b2tlbgAAAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAGxTZWVkIGJvdGggc2lkZXMgb2YgYSBtYXJrZXQgd2l0aCBiYWxhbmNlZCBsaXF1aWRpdHkuIFRoZSBwcm92aWRlcgpyZWNlaXZlcyBvbmUgTFAgc2hhcmUgcGVyIHRva2VuIGRlcG9zaXRlZC4AAAARcHJvdmlkZV9saXF1aWRpdHkAAAAAAAADAAAAAAAAAAhwcm92aWRlcgAAABMAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAAL",
+        "AAAAAAAAALJSZXR1cm4gYSBjb21taXRtZW50IHRoYXQgd2FzIG5ldmVyIHJldmVhbGVkLCBvbmNlIHRoZSByZXZlYWwgd2luZG93CmhhcyBjbG9zZWQuIFRoZSBwZW5hbHR5IHJhdGUgaW4gZm9yY2UgYXQgY29tbWl0IHRpbWUgaXMga2VwdCBhcwpwbGF0Zm9ybSBmZWVzIHVubGVzcyB0aGUgbWFya2V0IHdhcyBjYW5jZWxsZWQuAAAAAAARcmVmdW5kX2NvbW1pdG1lbnQAAAAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAAL",
+        "AAAAAAAAAIVSZXZlYWwgYSBjb21taXR0ZWQgcHJlZGljdGlvbiBiZXR3ZWVuIGNsb3NlX3RpbWUgYW5kIHJlc29sdmVfYWZ0ZXIuClRoZSBzdGFrZSBqb2lucyB0aGUgcG9vbCBhbmQgdGhlIHJlc3Qgb2YgdGhlIGRlcG9zaXQgaXMgcmV0dXJuZWQuAAAAAAAAEXJldmVhbF9wcmVkaWN0aW9uAAAAAAAABQAAAAAAAAAEdXNlcgAAABMAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAAAAAABHNpZGUAAAfQAAAADlByZWRpY3Rpb25TaWRlAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAC9TZXQgdGhlIHBlci1pbmNpZGVudCBpbnN1cmFuY2UgY2FwIChhZG1pbiBvbmx5KQAAAAARc2V0X2luc3VyYW5jZV9jYXAAAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAA2NhcAAAAAALAAAAAA==",
+        "AAAAAAAAAD9TZXQgdGhlIGdvdmVybmFuY2UgdG9rZW4gYWNjZXB0ZWQgYnkgYHN0YWtlYCAoYWRtaW4gb25seSwgb25jZSkAAAAAEXNldF9zdGFraW5nX3Rva2VuAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAA",
+        "AAAAAAAAAEZDb21wdXRlIHRoZSBjb21taXRtZW50IGhhc2ggZm9yIGEgdXNlcidzIGhpZGRlbiBwcmVkaWN0aW9uIG9uIGEgbWFya2V0AAAAAAASY29tcHV0ZV9jb21taXRtZW50AAAAAAAFAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAAAAAAEc2lkZQAAB9AAAAAOUHJlZGljdGlvblNpZGUAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD7gAAACA=",
+        "AAAAAAAAATFQdXQgYSByZXNvbHZlZCBtYXJrZXQncyBvdXRjb21lIHRvIGEgbmV3IHZvdGUgKHJlc29sdmVyIG9ubHkpLiBQYXlvdXRzCmFyZSBmcm96ZW4gdW50aWwgdGhlIHJlc29sdmVyIGNhbGxzIGByZXNvbHZlX21hcmtldGAgYWdhaW4sIHdoaWNoCnJlcGxhY2VzIG9yIHVwaG9sZHMgdGhlIG91dGNvbWUuIE9ubHkgcG9zc2libGUgb25jZSBwZXIgbWFya2V0LCBiZWZvcmUKYW55dGhpbmcgaGFzIGJlZW4gcGFpZCBvdXQgYW5kIGJlZm9yZSBhIGNvbmRpdGlvbmFsIG1hcmtldCBkZXBlbmRpbmcKb24gdGhlIG91dGNvbWUgaGFzIHJlc29sdmVkLgAAAAAAABJjb250ZXN0X3Jlc29sdXRpb24AAAAAAAIAAAAAAAAACHJlc29sdmVyAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAA=",
+        "AAAAAAAAADlHZXQgcGxhdGZvcm0gZmVlcyAoaW5jbHVkaW5nIHN3ZXB0IGR1c3QpIGhlbGQgZm9yIGEgdG9rZW4AAAAAAAASZ2V0X2NvbGxlY3RlZF9mZWVzAAAAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAACdHZXQgdGhlIGluc3VyYW5jZSBmdW5kIGhlbGQgZm9yIGEgdG9rZW4AAAAAEmdldF9pbnN1cmFuY2VfZnVuZAAAAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAEJHZXQgdGhlIHNoYXJlIG9mIHBsYXRmb3JtIGZlZXMgcGFpZCB0byByZWZlcnJlcnMgKGluIGJhc2lzIHBvaW50cykAAAAAABJnZXRfcmVmZXJyYWxfc2hhcmUAAAAAAAAAAAABAAAACw==",
+        "AAAAAAAAADBHZXQgYSByZWZlcnJlcidzIHZvbHVtZSBhbmQgcmV3YXJkcyBpbiBvbmUgdG9rZW4AAAASZ2V0X3JlZmVycmVyX3N0YXRzAAAAAAACAAAAAAAAAAhyZWZlcnJlcgAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAfQAAAADVJlZmVycmVyU3RhdHMAAAA=",
+        "AAAAAAAAAEtHZXQgbWFya2V0IGlkcyBvZiBhIHNlcmllcywgb2xkZXN0IGZpcnN0LCBmb3Igcm91bmRzIGBzdGFydC4uc3RhcnQgKyBsaW1pdGAAAAAAEmdldF9zZXJpZXNfbWFya2V0cwAAAAAAAwAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABgAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAAAY=",
+        "AAAAAAAAACxHZXQgYSB1c2VyJ3Mgc3Rha2VkIGdvdmVybmFuY2UgdG9rZW4gYmFsYW5jZQAAABJnZXRfc3Rha2VkX2JhbGFuY2UAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAD1TZXQgdGhlIHNoYXJlIG9mIHBsYXRmb3JtIGZlZXMgcGFpZCB0byByZWZlcnJlcnMgKGFkbWluIG9ubHkpAAAAAAAAEnNldF9yZWZlcnJhbF9zaGFyZQAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAVzaGFyZQAAAAAAAAsAAAAA",
+        "AAAAAAAAARpXaXRoZHJhdyBhIHByb3ZpZGVyJ3MgcHJvLXJhdGEgcmVzaWR1YWwgYW5kIGZlZSBjdXQgYWZ0ZXIgcmVzb2x1dGlvbiwKb3IgdGhlIGZ1bGwgZGVwb3NpdCBpZiB0aGUgbWFya2V0IHdhcyBjYW5jZWxsZWQuIENhbiBiZSBjYWxsZWQgYWdhaW4KbGF0ZXIgdG8gY29sbGVjdCBmZWVzIGFjY3J1ZWQgc2luY2UgdGhlIGxhc3Qgd2l0aGRyYXdhbC4gUGFzdCB0aGUgY2xhaW0KZGVhZGxpbmUgb25seSBmZWVzIGFyZSBwYWlkLCB0aGUgcmVzaWR1YWwgaXMgbGVmdCB0byBgc3dlZXBfdW5jbGFpbWVkYC4AAAAAABJ3aXRoZHJhd19saXF1aWRpdHkAAAAAAAIAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAAL",
+        "AAAAAAAAABpHZXQgd2hvIG1heSBjcmVhdGUgbWFya2V0cwAAAAAAE2dldF9jcmVhdGlvbl9wb2xpY3kAAAAAAAAAAAEAAAfQAAAADkNyZWF0aW9uUG9saWN5AAA=",
+        "AAAAAAAAAEhHZXQgdGhlIHNoYXJlIG9mIHBsYXRmb3JtIGZlZXMgc2V0IGFzaWRlIGZvciBpbnN1cmFuY2UgKGluIGJhc2lzIHBvaW50cykAAAATZ2V0X2luc3VyYW5jZV9zaGFyZQAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAADFHZXQga2VlcGVyIHRpcCByYXRlIChpbiBiYXNpcyBwb2ludHMgb2Ygd2lubmluZ3MpAAAAAAAAE2dldF9rZWVwZXJfdGlwX3JhdGUAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAACtHZXQgdXNlcidzIHByZWRpY3Rpb24gZm9yIGEgc3BlY2lmaWMgbWFya2V0AAAAABNnZXRfdXNlcl9wcmVkaWN0aW9uAAAAAAIAAAAAAAAABHVzZXIAAAATAAAAAAAAAAltYXJrZXRfaWQAAAAAAAAGAAAAAQAAA+gAAAfQAAAAClByZWRpY3Rpb24AAA==",
+        "AAAAAAAAAEFDaGVjayB3aGV0aGVyIGEgbWFya2V0IGhhcyBiZWVuIGNhbmNlbGxlZCAoc3Rha2VzIGFyZSByZWZ1bmRhYmxlKQAAAAAAABNpc19tYXJrZXRfY2FuY2VsbGVkAAAAAAEAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAABAAAAAQ==",
+        "AAAAAAAAACdTZXQgd2hvIG1heSBjcmVhdGUgbWFya2V0cyAoYWRtaW4gb25seSkAAAAAE3NldF9jcmVhdGlvbl9wb2xpY3kAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAZwb2xpY3kAAAAAB9AAAAAOQ3JlYXRpb25Qb2xpY3kAAAAAAAA=",
+        "AAAAAAAAAENTZXQgdGhlIHNoYXJlIG9mIHBsYXRmb3JtIGZlZXMgc2V0IGFzaWRlIGZvciBpbnN1cmFuY2UgKGFkbWluIG9ubHkpAAAAABNzZXRfaW5zdXJhbmNlX3NoYXJlAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAFc2hhcmUAAAAAAAALAAAAAA==",
+        "AAAAAAAAACBTZXQga2VlcGVyIHRpcCByYXRlIChhZG1pbiBvbmx5KQAAABNzZXRfa2VlcGVyX3RpcF9yYXRlAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIdGlwX3JhdGUAAAALAAAAAA==",
+        "AAAAAAAAACpHZXQgYSBmZWUgaW5jcmVhc2UgdGhhdCBoYXNuJ3QgYXBwbGllZCB5ZXQAAAAAABRnZXRfcGVuZGluZ19mZWVfcmF0ZQAAAAAAAAABAAAD6AAAB9AAAAAOUGVuZGluZ0ZlZVJhdGUAAA==",
+        "AAAAAAAAACJHZXQgcGF5b3V0IGFjY291bnRpbmcgZm9yIGEgbWFya2V0AAAAAAAVZ2V0X21hcmtldF9zZXR0bGVtZW50AAAAAAAAAQAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAfQAAAAEE1hcmtldFNldHRsZW1lbnQ=",
+        "AAAAAAAAADtHZXQgYSBzd2l0Y2ggdG8gdGhlIGdyb3NzIGZlZSBiYXNpcyB0aGF0IGhhc24ndCBhcHBsaWVkIHlldAAAAAAVZ2V0X3BlbmRpbmdfZmVlX2Jhc2lzAAAAAAAAAAAAAAEAAAPoAAAH0AAAAA9QZW5kaW5nRmVlQmFzaXMA",
+        "AAAAAAAAAEpHZXQgcHJvcG9zYWxzIHRoYXQgY2FuIHN0aWxsIGJlIGFwcHJvdmVkLCBhbW9uZyBpZHMgYHN0YXJ0Li5zdGFydCArIGxpbWl0YAAAAAAAFWdldF9wZW5kaW5nX3Byb3Bvc2FscwAAAAAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAACFByb3Bvc2Fs",
         "AAAAAAAAABVHZXQgcGxhdGZvcm0gZmVlIHJhdGUAAAAAAAAVZ2V0X3BsYXRmb3JtX2ZlZV9yYXRlAAAAAAAAAAAAAAEAAAAL",
-        "AAAAAAAAACJTZXQgcGxhdGZvcm0gZmVlIHJhdGUgKGFkbWluIG9ubHkpAAAAAAAVc2V0X3BsYXRmb3JtX2ZlZV9yYXRlAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhmZWVfcmF0ZQAAAAsAAAAA",
-        "AAAAAAAAABlVcGRhdGUgYWRtaW4gKGFkbWluIG9ubHkpAAAAAAAADHVwZGF0ZV9hZG1pbgAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAABFHZXQgYWRtaW4gYWRkcmVzcwAAAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAB1HZXQgd2lubmluZyBzaWRlIGZvciBhIG1hcmtldAAAAAAAABBnZXRfd2lubmluZ19zaWRlAAAAAQAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAPoAAAH0AAAAA5QcmVkaWN0aW9uU2lkZQAA" ]),
+        "AAAAAAAAAC5HZXQgdGhlIGRlZmF1bHQgcmVzb2x1dGlvbiB3aW5kb3cgKGluIHNlY29uZHMpAAAAAAAVZ2V0X3Jlc29sdXRpb25fd2luZG93AAAAAAAAAAAAAAEAAAAG",
+        "AAAAAAAAAGpTZXQgcGxhdGZvcm0gZmVlIHJhdGUgKGFkbWluIG9ubHksIHRocm91Z2ggYSBwcm9wb3NhbCB3aGVuIG11bHRpc2lnIGlzIGVuYWJsZWQpLgpJbmNyZWFzZXMgYXJlIHRpbWVsb2NrZWQuAAAAAAAVc2V0X3BsYXRmb3JtX2ZlZV9yYXRlAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhmZWVfcmF0ZQAAAAsAAAAA",
+        "AAAAAAAAAGZTZXQgdGhlIGRlZmF1bHQgcmVzb2x1dGlvbiB3aW5kb3cgYXBwbGllZCB0byBtYXJrZXRzIGNyZWF0ZWQgd2l0aG91dAphbiBleHBsaWNpdCBkZWFkbGluZSAoYWRtaW4gb25seSkAAAAAABVzZXRfcmVzb2x1dGlvbl93aW5kb3cAAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABndpbmRvdwAAAAAABgAAAAA=",
+        "AAAAAAAAADJXaXRoZHJhdyBhIHJlZmVycmVyJ3MgYWNjcnVlZCByZXdhcmRzIGluIG9uZSB0b2tlbgAAAAAAFmNsYWltX3JlZmVycmFsX3Jld2FyZHMAAAAAAAIAAAAAAAAACHJlZmVycmVyAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAADhHZXQgdGhlIG51bWJlciBvZiBhIGNyZWF0b3IncyBtYXJrZXRzIHRoYXQgd2VyZSByZXNvbHZlZAAAABZnZXRfY3JlYXRvcl9yZXB1dGF0aW9uAAAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAABA==",
+        "AAAAAAAAAEBHZXQgdGhlIGZlZSByYXRlIGNoYXJnZWQgdG8gYSB1c2VyIG9uIHdpbm5pbmdzIChpbiBiYXNpcyBwb2ludHMpAAAAFmdldF9lZmZlY3RpdmVfZmVlX3JhdGUAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAEJVcGRhdGUgbWFya2V0IG1ldGFkYXRhIChjcmVhdG9yIG9ubHksIGJlZm9yZSB0aGUgZmlyc3QgcHJlZGljdGlvbikAAAAAABZ1cGRhdGVfbWFya2V0X21ldGFkYXRhAAAAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAACW1hcmtldF9pZAAAAAAAAAYAAAAAAAAACG1ldGFkYXRhAAAH0AAAAA5NYXJrZXRNZXRhZGF0YQAAAAAAAA==",
+        "AAAAAAAAADtHZXQgdGhlIHBlbmFsdHkgb24gdW5yZXZlYWxlZCBjb21taXRtZW50cyAoaW4gYmFzaXMgcG9pbnRzKQAAAAAXZ2V0X2NvbW1pdF9wZW5hbHR5X3JhdGUAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAAIpHZXQgaWRzIG9mIG1hcmtldHMgaW4gYSBjYXRlZ29yeS4gU2NhbnMgYXQgbW9zdCBgbGltaXRgIG1hcmtldCBpZHMKc3RhcnRpbmcgZnJvbSBgc3RhcnRgLCBzbyBjYWxsZXJzIHBhZ2UgdGhyb3VnaCB3aXRoIGluY3JlYXNpbmcgYHN0YXJ0YC4AAAAAABdnZXRfbWFya2V0c19ieV9jYXRlZ29yeQAAAAADAAAAAAAAAAhjYXRlZ29yeQAAABAAAAAAAAAABXN0YXJ0AAAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAAG",
+        "AAAAAAAAADtDaGVjayB3aGV0aGVyIGEgbWFya2V0J3MgcmVzb2x1dGlvbiBpcyB1bmRlciBhIGRpc3B1dGUgdm90ZQAAAAAXaXNfcmVzb2x1dGlvbl9jb250ZXN0ZWQAAAAAAQAAAAAAAAAJbWFya2V0X2lkAAAAAAAABgAAAAEAAAAB",
+        "AAAAAAAAADZTZXQgdGhlIHBlbmFsdHkgb24gdW5yZXZlYWxlZCBjb21taXRtZW50cyAoYWRtaW4gb25seSkAAAAAABdzZXRfY29tbWl0X3BlbmFsdHlfcmF0ZQAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABHJhdGUAAAALAAAAAA==",
+        "AAAAAAAAAINDcmVhdGUgYSBtYXJrZXQgdGhhdCBvbmx5IHNldHRsZXMgaWYgYSBwYXJlbnQgbWFya2V0IHJlc29sdmVzIHRvIHRoZQpyZXF1aXJlZCBvdXRjb21lLCBhbmQgaXMgY2FuY2VsbGVkIHdpdGggZnVsbCByZWZ1bmRzIG90aGVyd2lzZQAAAAAZY3JlYXRlX2NvbmRpdGlvbmFsX21hcmtldAAAAAAAAAQAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAGcGFyYW1zAAAAAAfQAAAADE1hcmtldFBhcmFtcwAAAAAAAAAQcGFyZW50X21hcmtldF9pZAAAAAYAAAAAAAAADnBhcmVudF9vdXRjb21lAAAAAAfQAAAADlByZWRpY3Rpb25TaWRlAAAAAAABAAAABg==",
+        "AAAAAAAAADRHZXQgdGhlIG1hcmtldCBvcGVuZWQgYnkgdGhlIGxhdGVzdCByb2xsIG9mIGEgc2VyaWVzAAAAGWdldF9zZXJpZXNfY3VycmVudF9tYXJrZXQAAAAAAAABAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAGAAAAAQAAA+gAAAAG",
+        "AAAAAAAAADZHZXQgdGhlIHJlcHV0YXRpb24gY3JlYXRvcnMgbmVlZCB1bmRlciB0aGUgb3BlbiBwb2xpY3kAAAAAABpnZXRfbWluX2NyZWF0b3JfcmVwdXRhdGlvbgAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAF5TZXQgdGhlIHJlcHV0YXRpb24gY3JlYXRvcnMgbmVlZCB1bmRlciB0aGUgb3BlbiBwb2xpY3kgKGFkbWluIG9ubHkpLgowIGRpc2FibGVzIHRoZSB0aHJlc2hvbGQuAAAAAAAac2V0X21pbl9jcmVhdG9yX3JlcHV0YXRpb24AAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAObWluX3JlcHV0YXRpb24AAAAAAAQAAAAA" ]),
       options
     )
   }
   public readonly fromJSON = {
-    initialize: this.txFromJSON<null>,
-        create_market: this.txFromJSON<u64>,
+    stake: this.txFromJSON<null>,
         predict: this.txFromJSON<null>,
-        resolve_market: this.txFromJSON<null>,
-        claim_winnings: this.txFromJSON<i128>,
+        unstake: this.txFromJSON<null>,
+        get_flag: this.txFromJSON<Option<MarketFlag>>,
+        get_odds: this.txFromJSON<MarketOdds>,
+        claim_for: this.txFromJSON<i128>,
+        get_admin: this.txFromJSON<string>,
+        claim_many: this.txFromJSON<Array<ClaimResult>>,
         get_market: this.txFromJSON<Market>,
-        get_user_prediction: this.txFromJSON<Option<Prediction>>,
+        get_series: this.txFromJSON<MarketSeries>,
+        initialize: this.txFromJSON<null>,
+        sweep_dust: this.txFromJSON<i128>,
+        flag_market: this.txFromJSON<u32>,
+        get_signers: this.txFromJSON<Array<string>>,
+        roll_series: this.txFromJSON<u64>,
+        set_signers: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<null>,
+        forward_fees: this.txFromJSON<i128>,
+        get_proposal: this.txFromJSON<Proposal>,
+        get_referrer: this.txFromJSON<Option<string>>,
+        get_resolver: this.txFromJSON<Option<string>>,
+        get_treasury: this.txFromJSON<Option<string>>,
+        is_moderator: this.txFromJSON<boolean>,
+        quote_payout: this.txFromJSON<i128>,
+        set_resolver: this.txFromJSON<null>,
+        set_treasury: this.txFromJSON<null>,
+        update_admin: this.txFromJSON<null>,
+        create_market: this.txFromJSON<u64>,
+        create_series: this.txFromJSON<u64>,
+        delist_market: this.txFromJSON<null>,
+        expire_market: this.txFromJSON<null>,
+        get_claimable: this.txFromJSON<i128>,
+        get_delisting: this.txFromJSON<Option<Delisting>>,
+        get_fee_basis: this.txFromJSON<FeeBasis>,
+        get_fee_tiers: this.txFromJSON<Array<FeeTier>>,
+        get_threshold: this.txFromJSON<u32>,
+        set_fee_basis: this.txFromJSON<null>,
+        set_fee_tiers: this.txFromJSON<null>,
+        set_moderator: this.txFromJSON<null>,
+        approve_action: this.txFromJSON<null>,
+        claim_winnings: this.txFromJSON<i128>,
+        get_commitment: this.txFromJSON<Option<Commitment>>,
+        get_flag_count: this.txFromJSON<u32>,
+        get_resolution: this.txFromJSON<Option<Resolution>>,
+        is_allowlisted: this.txFromJSON<boolean>,
+        propose_action: this.txFromJSON<u64>,
+        resolve_market: this.txFromJSON<null>,
+        get_lp_position: this.txFromJSON<Option<LpPosition>>,
+        get_user_volume: this.txFromJSON<i128>,
+        set_allowlisted: this.txFromJSON<null>,
+        sweep_unclaimed: this.txFromJSON<i128>,
+        get_claim_window: this.txFromJSON<u64>,
+        get_lp_fee_share: this.txFromJSON<i128>,
         get_market_count: this.txFromJSON<u64>,
+        get_series_count: this.txFromJSON<u64>,
+        get_winning_side: this.txFromJSON<Option<PredictionSide>>,
+        insurance_payout: this.txFromJSON<i128>,
+        set_claim_window: this.txFromJSON<null>,
+        set_lp_fee_share: this.txFromJSON<null>,
+        commit_prediction: this.txFromJSON<null>,
+        get_insurance_cap: this.txFromJSON<i128>,
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        get_staking_token: this.txFromJSON<Option<string>>,
+        provide_liquidity: this.txFromJSON<i128>,
+        refund_commitment: this.txFromJSON<i128>,
+        reveal_prediction: this.txFromJSON<null>,
+        set_insurance_cap: this.txFromJSON<null>,
+        set_staking_token: this.txFromJSON<null>,
+        compute_commitment: this.txFromJSON<Buffer>,
+        contest_resolution: this.txFromJSON<null>,
+        get_collected_fees: this.txFromJSON<i128>,
+        get_insurance_fund: this.txFromJSON<i128>,
+        get_referral_share: this.txFromJSON<i128>,
+        get_referrer_stats: this.txFromJSON<ReferrerStats>,
+        get_series_markets: this.txFromJSON<Array<u64>>,
+        get_staked_balance: this.txFromJSON<i128>,
+        set_referral_share: this.txFromJSON<null>,
+        withdraw_liquidity: this.txFromJSON<i128>,
+        get_creation_policy: this.txFromJSON<CreationPolicy>,
+        get_insurance_share: this.txFromJSON<i128>,
+        get_keeper_tip_rate: this.txFromJSON<i128>,
+        get_user_prediction: this.txFromJSON<Option<Prediction>>,
+        is_market_cancelled: this.txFromJSON<boolean>,
+        set_creation_policy: this.txFromJSON<null>,
+        set_insurance_share: this.txFromJSON<null>,
+        set_keeper_tip_rate: this.txFromJSON<null>,
+        get_pending_fee_rate: this.txFromJSON<Option<PendingFeeRate>>,
+        get_market_settlement: this.txFromJSON<MarketSettlement>,
+        get_pending_fee_basis: this.txFromJSON<Option<PendingFeeBasis>>,
+        get_pending_proposals: this.txFromJSON<Array<Proposal>>,
         get_platform_fee_rate: this.txFromJSON<i128>,
+        get_resolution_window: this.txFromJSON<u64>,
         set_platform_fee_rate: this.txFromJSON<null>,
-        update_admin: this.txFromJSON<null>,
-        get_admin: this.txFromJSON<string>,
-        get_winning_side: this.txFromJSON<Option<PredictionSide>>
+        set_resolution_window: this.txFromJSON<null>,
+        claim_referral_rewards: this.txFromJSON<i128>,
+        get_creator_reputation: this.txFromJSON<u32>,
+        get_effective_fee_rate: this.txFromJSON<i128>,
+        update_market_metadata: this.txFromJSON<null>,
+        get_commit_penalty_rate: this.txFromJSON<i128>,
+        get_markets_by_category: this.txFromJSON<Array<u64>>,
+        is_resolution_contested: this.txFromJSON<boolean>,
+        set_commit_penalty_rate: this.txFromJSON<null>,
+        create_conditional_market: this.txFromJSON<u64>,
+        get_series_current_market: this.txFromJSON<Option<u64>>,
+        get_min_creator_reputation: this.txFromJSON<u32>,
+        set_min_creator_reputation: this.txFromJSON<null>
   }
 }
\ No newline at end of file